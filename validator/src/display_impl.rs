@@ -4,10 +4,11 @@ use crate::{ValidationError, ValidationErrors, ValidationErrorsKind};
 
 impl fmt::Display for ValidationError {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(msg) = self.message.as_ref() {
-            write!(fmt, "{}", msg)
-        } else {
-            write!(fmt, "Validation error: {} [{:?}]", self.code, self.params)
+        match (self.message.as_ref(), self.is_warning()) {
+            (Some(msg), false) => write!(fmt, "{}", msg),
+            (Some(msg), true) => write!(fmt, "{} (warning)", msg),
+            (None, false) => write!(fmt, "Validation error: {} [{:?}]", self.code, self.params),
+            (None, true) => write!(fmt, "Validation warning: {} [{:?}]", self.code, self.params),
         }
     }
 }
@@ -25,7 +26,10 @@ fn display_errors(
         let mut full_path = String::new();
         write!(&mut full_path, "{}.", path)?;
         let base_len = full_path.len();
-        for (path, err) in errs.errors() {
+        for (idx, (path, err)) in errs.errors().iter().enumerate() {
+            if idx > 0 {
+                writeln!(fmt)?;
+            }
             write!(&mut full_path, "{}", path)?;
             display_errors(fmt, err, &full_path)?;
             full_path.truncate(base_len);
@@ -51,7 +55,10 @@ fn display_errors(
             let mut full_path = String::new();
             write!(&mut full_path, "{}", path)?;
             let base_len = full_path.len();
-            for (idx, err) in errs.iter() {
+            for (i, (idx, err)) in errs.iter().enumerate() {
+                if i > 0 {
+                    writeln!(fmt)?;
+                }
                 write!(&mut full_path, "[{}]", idx)?;
                 display_struct(fmt, err, &full_path)?;
                 full_path.truncate(base_len);
@@ -0,0 +1,380 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use indexmap::IndexMap;
+use serde_derive::Serialize;
+use serde_json::Value;
+
+use crate::types::{Severity, ValidationError, ValidationErrors, ValidationErrorsKind};
+
+/// A step in the path to an error: a field name or the index of a list item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment<'a> {
+    Field(&'a str),
+    Index(usize),
+}
+
+/// The path as displayed, eg `contacts[0].email`
+fn dotted_path(path: &[Segment]) -> String {
+    let mut out = String::new();
+    for segment in path {
+        match segment {
+            Segment::Field(field) => {
+                if !out.is_empty() {
+                    out.push('.');
+                }
+                out.push_str(field);
+            }
+            Segment::Index(index) => {
+                let _ = write!(out, "[{}]", index);
+            }
+        }
+    }
+    out
+}
+
+/// The JSON pointer (RFC 6901) of the path appended to `base`. `__all__` points to the struct
+/// it is in.
+fn json_pointer(base: &str, path: &[Segment]) -> String {
+    let mut out = base.to_string();
+    for segment in path {
+        match segment {
+            Segment::Field("__all__") => (),
+            Segment::Field(field) => {
+                out.push('/');
+                out.push_str(&field.replace('~', "~0").replace('/', "~1"));
+            }
+            Segment::Index(index) => {
+                let _ = write!(out, "/{}", index);
+            }
+        }
+    }
+    out
+}
+
+/// The message of the error or its code if it doesn't have one
+fn reason(error: &ValidationError) -> String {
+    match error.message {
+        Some(ref message) => message.to_string(),
+        None => error.code.to_string(),
+    }
+}
+
+fn walk<'a, F>(errors: &'a ValidationErrors, path: &mut Vec<Segment<'a>>, f: &mut F)
+where
+    F: FnMut(&[Segment<'a>], &'a ValidationError),
+{
+    for (field, kind) in errors.errors() {
+        path.push(Segment::Field(field));
+        match kind {
+            ValidationErrorsKind::Field(errs) => errs.iter().for_each(|err| f(path, err)),
+            ValidationErrorsKind::Struct(errs) => walk(errs, path, f),
+            ValidationErrorsKind::List(list) => {
+                for (index, errs) in list {
+                    path.push(Segment::Index(*index));
+                    walk(errs, path, f);
+                    path.pop();
+                }
+            }
+        }
+        path.pop();
+    }
+}
+
+/// An [RFC 7807](https://tools.ietf.org/html/rfc7807) problem details object, as returned by
+/// `ValidationErrors::to_problem_details`. The fields can be changed before serializing it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(rename = "invalid-params")]
+    pub invalid_params: Vec<InvalidParam>,
+}
+
+/// An entry of the `invalid-params` extension of `ProblemDetails`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InvalidParam {
+    /// The path to the field, eg `contacts[0].email`
+    pub name: String,
+    /// The message of the error or its code if it doesn't have one
+    pub reason: String,
+    pub code: String,
+    /// Only serialized for warnings
+    #[serde(default, skip_serializing_if = "Severity::is_error")]
+    pub severity: Severity,
+}
+
+/// A [JSON:API](https://jsonapi.org/format/#error-objects) document with only `errors`, as
+/// returned by `ValidationErrors::to_json_api`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonApiErrors {
+    pub errors: Vec<JsonApiError>,
+}
+
+/// A JSON:API error object
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonApiError {
+    pub status: String,
+    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub source: JsonApiErrorSource,
+    /// The params of the validation error, along with a `severity` of `warning` for a warning
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub meta: IndexMap<Cow<'static, str>, Value>,
+}
+
+/// Where a JSON:API error comes from in the request document
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonApiErrorSource {
+    /// A JSON pointer, eg `/data/attributes/contacts/0/email`
+    pub pointer: String,
+}
+
+impl ValidationErrors {
+    /// Returns every error with the path to its field, eg `address.zip` or `contacts[0].email`,
+    /// in the order of the errors
+    pub fn flatten(&self) -> Vec<(String, &ValidationError)> {
+        let mut flat = vec![];
+        walk(self, &mut vec![], &mut |path, err| flat.push((dotted_path(path), err)));
+        flat
+    }
+
+    /// Returns the messages of the errors keyed by the path to their field, eg
+    /// `{"address.zip": ["too short"]}`. An error without a message is represented by its code.
+    /// The warnings are left out, see `to_flat_warning_map`.
+    pub fn to_flat_map(&self) -> IndexMap<String, Vec<String>> {
+        self.flat_map_of(Severity::Error)
+    }
+
+    /// Same as `to_flat_map` with only the messages of the warnings
+    pub fn to_flat_warning_map(&self) -> IndexMap<String, Vec<String>> {
+        self.flat_map_of(Severity::Warning)
+    }
+
+    fn flat_map_of(&self, severity: Severity) -> IndexMap<String, Vec<String>> {
+        let mut map: IndexMap<String, Vec<String>> = IndexMap::new();
+        walk(self, &mut vec![], &mut |path, err| {
+            if err.severity == severity {
+                map.entry(dotted_path(path)).or_default().push(reason(err));
+            }
+        });
+        map
+    }
+
+    /// Returns an RFC 7807 problem details object for a `422 Unprocessable Entity` response
+    /// with an entry in `invalid-params` for every error, the ones of the warnings having a
+    /// `severity` of `warning`
+    pub fn to_problem_details(&self) -> ProblemDetails {
+        let mut invalid_params = vec![];
+        walk(self, &mut vec![], &mut |path, err| {
+            invalid_params.push(InvalidParam {
+                name: dotted_path(path),
+                reason: reason(err),
+                code: err.code.to_string(),
+                severity: err.severity,
+            })
+        });
+
+        ProblemDetails {
+            problem_type: "about:blank".to_string(),
+            title: "Unprocessable Entity".to_string(),
+            status: 422,
+            detail: None,
+            invalid_params,
+        }
+    }
+
+    /// Returns a JSON:API document with an error object for every error, with a `422` status and
+    /// a `source.pointer` under `/data/attributes`. The `meta` of a warning has a `severity` of
+    /// `warning` along with the params.
+    pub fn to_json_api(&self) -> JsonApiErrors {
+        self.to_json_api_with_pointer("/data/attributes")
+    }
+
+    /// Same as `to_json_api` with the pointers under the given base, eg `/data/relationships`
+    /// or `""` for the root of the document
+    pub fn to_json_api_with_pointer(&self, base: &str) -> JsonApiErrors {
+        let mut errors = vec![];
+        walk(self, &mut vec![], &mut |path, err| {
+            let mut meta = err.params.clone();
+            if err.is_warning() {
+                meta.insert(Cow::from("severity"), Value::from("warning"));
+            }
+            errors.push(JsonApiError {
+                status: "422".to_string(),
+                code: err.code.to_string(),
+                detail: err.message.as_ref().map(|message| message.to_string()),
+                source: JsonApiErrorSource { pointer: json_pointer(base, path) },
+                meta,
+            })
+        });
+        JsonApiErrors { errors }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use serde_json::json;
+
+    use crate::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+    fn error(code: &'static str, message: Option<&'static str>) -> ValidationError {
+        let mut err = ValidationError::new(code);
+        err.message = message.map(Cow::from);
+        err
+    }
+
+    fn errors() -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        errors.add("name", error("length", Some("too short")));
+        errors.add("name", error("regex", None));
+
+        let mut address = ValidationErrors::new();
+        address.add("zip", error("regex", Some("invalid zip")));
+        address.add("__all__", error("schema", Some("unknown address")));
+        errors.merge_at(&["address"], address).unwrap();
+
+        let mut contact = ValidationErrors::new();
+        let mut email = error("email", Some("invalid email"));
+        email.add_param(Cow::from("value"), "bob");
+        contact.add("e/mail", email);
+        let list = vec![(2, Box::new(contact))].into_iter().collect();
+        errors.add_nested_at("contacts", ValidationErrorsKind::List(list)).unwrap();
+        errors
+    }
+
+    #[test]
+    fn test_flatten() {
+        let errors = errors();
+        let paths: Vec<_> =
+            errors.flatten().into_iter().map(|(path, err)| (path, err.code.to_string())).collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("name".to_string(), "length".to_string()),
+                ("name".to_string(), "regex".to_string()),
+                ("address.zip".to_string(), "regex".to_string()),
+                ("address.__all__".to_string(), "schema".to_string()),
+                ("contacts[2].e/mail".to_string(), "email".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_flat_map() {
+        assert_eq!(
+            serde_json::to_value(errors().to_flat_map()).unwrap(),
+            json!({
+                "name": ["too short", "regex"],
+                "address.zip": ["invalid zip"],
+                "address.__all__": ["unknown address"],
+                "contacts[2].e/mail": ["invalid email"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_problem_details() {
+        assert_eq!(
+            serde_json::to_value(errors().to_problem_details()).unwrap(),
+            json!({
+                "type": "about:blank",
+                "title": "Unprocessable Entity",
+                "status": 422,
+                "invalid-params": [
+                    { "name": "name", "reason": "too short", "code": "length" },
+                    { "name": "name", "reason": "regex", "code": "regex" },
+                    { "name": "address.zip", "reason": "invalid zip", "code": "regex" },
+                    { "name": "address.__all__", "reason": "unknown address", "code": "schema" },
+                    { "name": "contacts[2].e/mail", "reason": "invalid email", "code": "email" },
+                ],
+            })
+        );
+
+        let mut problem = errors().to_problem_details();
+        problem.problem_type = "https://example.com/probs/invalid".to_string();
+        problem.detail = Some("The signup is invalid".to_string());
+        let value = serde_json::to_value(problem).unwrap();
+        assert_eq!(value["type"], "https://example.com/probs/invalid");
+        assert_eq!(value["detail"], "The signup is invalid");
+    }
+
+    #[test]
+    fn test_to_json_api() {
+        let value = serde_json::to_value(errors().to_json_api()).unwrap();
+        let errors = value["errors"].as_array().unwrap();
+        assert_eq!(errors.len(), 5);
+        assert_eq!(
+            errors[0],
+            json!({
+                "status": "422",
+                "code": "length",
+                "detail": "too short",
+                "source": { "pointer": "/data/attributes/name" },
+            })
+        );
+        // no message
+        assert!(errors[1].get("detail").is_none());
+        assert_eq!(errors[2]["source"]["pointer"], "/data/attributes/address/zip");
+        // `__all__` points to its struct
+        assert_eq!(errors[3]["source"]["pointer"], "/data/attributes/address");
+        // the pointer is escaped and the params are in `meta`
+        assert_eq!(errors[4]["source"]["pointer"], "/data/attributes/contacts/2/e~1mail");
+        assert_eq!(errors[4]["meta"], json!({ "value": "bob" }));
+    }
+
+    #[test]
+    fn test_to_json_api_with_pointer() {
+        let value = serde_json::to_value(errors().to_json_api_with_pointer("")).unwrap();
+        assert_eq!(value["errors"][2]["source"]["pointer"], "/address/zip");
+        assert_eq!(value["errors"][3]["source"]["pointer"], "/address");
+
+        // a top-level `__all__` points to the base
+        let mut errors = ValidationErrors::new();
+        errors.add("__all__", error("schema", None));
+        let value = serde_json::to_value(errors.to_json_api()).unwrap();
+        assert_eq!(value["errors"][0]["source"]["pointer"], "/data/attributes");
+    }
+
+    #[test]
+    fn test_warnings() {
+        let mut errors = ValidationErrors::new();
+        errors.add("name", error("length", Some("too short")));
+        let mut warning = ValidationError::warning("length");
+        warning.message = Some(Cow::from("consider a longer name"));
+        errors.add("name", warning);
+
+        assert_eq!(
+            serde_json::to_value(errors.to_flat_map()).unwrap(),
+            json!({ "name": ["too short"] })
+        );
+        assert_eq!(
+            serde_json::to_value(errors.to_flat_warning_map()).unwrap(),
+            json!({ "name": ["consider a longer name"] })
+        );
+
+        let value = serde_json::to_value(errors.to_problem_details()).unwrap();
+        assert!(value["invalid-params"][0].get("severity").is_none());
+        assert_eq!(value["invalid-params"][1]["severity"], "warning");
+
+        let value = serde_json::to_value(errors.to_json_api()).unwrap();
+        assert!(value["errors"][0].get("meta").is_none());
+        assert_eq!(value["errors"][1]["meta"], json!({ "severity": "warning" }));
+    }
+
+    #[test]
+    fn test_empty_errors() {
+        let errors = ValidationErrors::new();
+        assert!(errors.flatten().is_empty());
+        assert!(errors.to_flat_map().is_empty());
+        assert!(errors.to_problem_details().invalid_params.is_empty());
+        assert!(errors.to_json_api().errors.is_empty());
+    }
+}
@@ -41,18 +41,34 @@
 //! | ----------------------- | ----------------------------------------------------- |
 //! | `email`                 |                                                       |
 //! | `url`                   |                                                       |
+//! | `hostname`              |                                                       |
+//! | `domain`                | (`public_suffix` needs the feature `public_suffix`)   |
 //! | `length`                |                                                       |
 //! | `range`                 |                                                       |
 //! | `must_match`            |                                                       |
 //! | `contains`              |                                                       |
 //! | `does_not_contain`      |                                                       |
 //! | `custom`                |                                                       |
+//! | `starts_with`           |                                                       |
+//! | `ends_with`             |                                                       |
 //! | `regex`                 |                                                       |
+//! | `ascii`                 |                                                       |
+//! | `alphanumeric`          |                                                       |
+//! | `alpha`                 |                                                       |
+//! | `numeric`               |                                                       |
+//! | `lowercase`             |                                                       |
+//! | `uppercase`             |                                                       |
+//! | `printable`             |                                                       |
+//! | `unique`                |                                                       |
+//! | `one_of`                |                                                       |
+//! | `none_of`               |                                                       |
 //! | `credit_card`           | (Requires the feature `card` to be enabled)           |
 //! | `phone`                 | (Requires the feature `phone` to be enabled)          |
 //! | `non_control_character` | (Required the feature `unic` to be enabled)           |
 //! | `nested`                | (Uses the validation of the field type it self)       |
 //! | `required`              |                                                       |
+//! | `non_empty`             |                                                       |
+//! | `not_blank`             |                                                       |
 //!
 //! [Checkout the project README of an in-depth usage description with examples.](https://github.com/Keats/validator/blob/master/README.md)
 //!
@@ -64,30 +80,67 @@
 //! validator = { version = "0.12", features = ["derive"] }
 //! ```
 
+extern crate indexmap_crate as indexmap;
+
 mod display_impl;
+mod formats;
+mod rules;
+mod sanitize;
+pub mod tagged;
 mod traits;
 mod types;
 mod validation;
 
+pub use validation::affix::{validate_ends_with, validate_starts_with};
 #[cfg(feature = "card")]
 pub use validation::cards::validate_credit_card;
+pub use validation::char_class::{first_invalid_char, validate_char_class, CharClass};
 pub use validation::contains::validate_contains;
 pub use validation::does_not_contain::validate_does_not_contain;
 pub use validation::email::validate_email;
+#[cfg(feature = "public_suffix")]
+pub use validation::hostname::validate_public_domain;
+pub use validation::hostname::{validate_domain, validate_hostname};
 pub use validation::ip::{validate_ip, validate_ip_v4, validate_ip_v6};
-pub use validation::length::validate_length;
+pub use validation::length::{validate_length, validate_length_with_mode, LengthMode};
 pub use validation::must_match::validate_must_match;
 #[cfg(feature = "unic")]
 pub use validation::non_control_character::validate_non_control_character;
+pub use validation::non_empty::{validate_non_empty, validate_not_blank};
+pub use validation::one_of::{validate_none_of, validate_one_of};
 #[cfg(feature = "phone")]
 pub use validation::phone::validate_phone;
 pub use validation::range::validate_range;
 
 pub use validation::required::validate_required;
+pub use validation::unique::{duplicate_indices, validate_unique};
 pub use validation::urls::validate_url;
 
-pub use traits::{Contains, HasLen, Validate, ValidateArgs};
-pub use types::{ValidationError, ValidationErrors, ValidationErrorsKind};
+pub use formats::{InvalidParam, JsonApiError, JsonApiErrorSource, JsonApiErrors, ProblemDetails};
+pub use rules::{
+    ContainsRule, FieldRules, FlagRule, LengthRule, RangeRule, RegexRule, RuleSet, RuleSetError,
+};
+pub use sanitize::{
+    sanitize_collapse_whitespace, sanitize_lowercase, sanitize_nfc, sanitize_trim,
+    sanitize_uppercase, Sanitize, StringsMut,
+};
+pub use traits::{
+    validate_nested_args, Contains, CustomError, HasLen, Validate, ValidateArgs, ValidateNested,
+    ValidateWith,
+};
+pub use types::{MergeConflict, Severity, ValidationError, ValidationErrors, ValidationErrorsKind};
 
 #[cfg(feature = "derive")]
-pub use validator_derive::Validate;
+pub use validator_derive::{Sanitize, Validate};
+
+// Used by the code generated for inline regex patterns
+#[doc(hidden)]
+pub use lazy_static::lazy_static;
+#[doc(hidden)]
+pub use regex::Regex;
+// Used by the code generated for `one_of`/`none_of` paths
+#[doc(hidden)]
+pub use validation::one_of::one_of_path_values_must_implement_serialize;
+// Used by the bounds the derive infers for generic structs
+#[doc(hidden)]
+pub use serde;
@@ -0,0 +1,697 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+
+use indexmap::IndexMap;
+use regex::Regex;
+use serde_derive::Deserialize;
+use serde_json::Value;
+
+use crate::types::{ValidationError, ValidationErrors, ValidationErrorsKind};
+use crate::validation::contains::validate_contains;
+use crate::validation::email::validate_email;
+use crate::validation::length::validate_length;
+use crate::validation::range::validate_range;
+use crate::validation::urls::validate_url;
+
+/// A validator without arguments: either `true`/`false` or an object to customize the error
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum FlagRule {
+    Enabled(bool),
+    Custom { message: Option<String>, code: Option<String> },
+}
+
+/// The arguments of the `length` validator, see `validate_length`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LengthRule {
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+    pub equal: Option<u64>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+/// The arguments of the `range` validator, see `validate_range`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RangeRule {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub message: Option<String>,
+    pub code: Option<String>,
+}
+
+/// The `regex` validator: either the pattern or an object to customize the error
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum RegexRule {
+    Pattern(String),
+    Custom { pattern: String, message: Option<String>, code: Option<String> },
+}
+
+/// The `contains` validator: either the needle or an object to customize the error.
+/// Strings look for a substring, arrays for an element and objects for a key.
+///
+/// An object with only `needle`, `message` and `code` keys is always read as the customization,
+/// so a needle that is itself an object has to be given as `{ "needle": { ... } }`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum ContainsRule {
+    Custom { needle: Value, message: Option<String>, code: Option<String> },
+    Needle(Value),
+}
+
+/// The validators to run on a single value of a JSON document.
+///
+/// `fields` validates the keys of an object and `items` every element of an array, the same
+/// way `nested` works with the derive.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FieldRules {
+    pub required: Option<FlagRule>,
+    pub email: Option<FlagRule>,
+    pub url: Option<FlagRule>,
+    pub length: Option<LengthRule>,
+    pub range: Option<RangeRule>,
+    pub regex: Option<RegexRule>,
+    pub contains: Option<ContainsRule>,
+    pub fields: IndexMap<String, FieldRules>,
+    pub items: Option<Box<FieldRules>>,
+}
+
+/// Why a set of rules could not be turned into a `RuleSet`
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleSetError {
+    /// The path is not made of `.` separated keys, each optionally followed by `[]`
+    InvalidPath(String),
+    /// The same validator was given twice for the same value through different paths
+    DuplicateRule { path: String, rule: &'static str },
+    /// The regex of the value at the given path doesn't compile
+    InvalidRegex { path: String, error: String },
+}
+
+impl fmt::Display for RuleSetError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleSetError::InvalidPath(path) => write!(fmt, "invalid path `{}`", path),
+            RuleSetError::DuplicateRule { path, rule } => {
+                write!(fmt, "`{}` is defined more than once for `{}`", rule, path)
+            }
+            RuleSetError::InvalidRegex { path, error } => {
+                write!(fmt, "invalid regex for `{}`: {}", path, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RuleSetError {}
+
+/// A segment of a path: an object key or `[]` for every element of an array
+enum Segment<'a> {
+    Key(&'a str),
+    Items,
+}
+
+fn parse_path(path: &str) -> Result<Vec<Segment<'_>>, RuleSetError> {
+    let mut segments = vec![];
+    for part in path.split('.') {
+        let mut key = part;
+        let mut items = 0;
+        while let Some(rest) = key.strip_suffix("[]") {
+            key = rest;
+            items += 1;
+        }
+        if key.is_empty() || key.contains('[') || key.contains(']') {
+            return Err(RuleSetError::InvalidPath(path.to_string()));
+        }
+        segments.push(Segment::Key(key));
+        segments.extend((0..items).map(|_| Segment::Items));
+    }
+    Ok(segments)
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn merge_option<T>(
+    into: &mut Option<T>,
+    from: Option<T>,
+    path: &str,
+    rule: &'static str,
+) -> Result<(), RuleSetError> {
+    if from.is_some() {
+        if into.is_some() {
+            return Err(RuleSetError::DuplicateRule { path: path.to_string(), rule });
+        }
+        *into = from;
+    }
+    Ok(())
+}
+
+impl FieldRules {
+    /// Adds the rules of `other` to these ones, failing if a validator is set in both
+    fn merge(&mut self, other: FieldRules, path: &str) -> Result<(), RuleSetError> {
+        merge_option(&mut self.required, other.required, path, "required")?;
+        merge_option(&mut self.email, other.email, path, "email")?;
+        merge_option(&mut self.url, other.url, path, "url")?;
+        merge_option(&mut self.length, other.length, path, "length")?;
+        merge_option(&mut self.range, other.range, path, "range")?;
+        merge_option(&mut self.regex, other.regex, path, "regex")?;
+        merge_option(&mut self.contains, other.contains, path, "contains")?;
+
+        for (key, rules) in other.fields {
+            let field_path = join_path(path, &key);
+            self.fields.entry(key).or_default().merge(rules, &field_path)?;
+        }
+
+        if let Some(items) = other.items {
+            let items_path = format!("{}[]", path);
+            self.items.get_or_insert_with(Default::default).merge(*items, &items_path)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A validator ready to run, with its error customization
+#[derive(Debug, Clone)]
+struct Check {
+    kind: CheckKind,
+    message: Option<String>,
+    code: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+enum CheckKind {
+    Email,
+    Url,
+    Length { min: Option<u64>, max: Option<u64>, equal: Option<u64> },
+    Range { min: Option<f64>, max: Option<f64> },
+    Regex { regex: Regex, pattern: String },
+    Contains(Value),
+}
+
+/// The message and code of an enabled flag
+fn flag_customization(rule: Option<FlagRule>) -> Option<(Option<String>, Option<String>)> {
+    match rule? {
+        FlagRule::Enabled(false) => None,
+        FlagRule::Enabled(true) => Some((None, None)),
+        FlagRule::Custom { message, code } => Some((message, code)),
+    }
+}
+
+fn new_error(
+    default_code: &'static str,
+    message: &Option<String>,
+    code: &Option<String>,
+    value: &Value,
+) -> ValidationError {
+    let mut err = ValidationError::new(default_code);
+    if let Some(code) = code {
+        err.code = Cow::from(code.clone());
+    }
+    err.message = message.clone().map(Cow::from);
+    err.add_param(Cow::from("value"), value);
+    err
+}
+
+impl Check {
+    fn flag(kind: CheckKind, rule: Option<FlagRule>) -> Option<Check> {
+        flag_customization(rule).map(|(message, code)| Check { kind, message, code })
+    }
+
+    fn default_code(&self) -> &'static str {
+        match self.kind {
+            CheckKind::Email => "email",
+            CheckKind::Url => "url",
+            CheckKind::Length { .. } => "length",
+            CheckKind::Range { .. } => "range",
+            CheckKind::Regex { .. } => "regex",
+            CheckKind::Contains(_) => "contains",
+        }
+    }
+
+    /// Any value of a type the validator doesn't apply to is invalid
+    fn is_valid(&self, value: &Value) -> bool {
+        match (&self.kind, value) {
+            (CheckKind::Email, Value::String(s)) => validate_email(s),
+            (CheckKind::Url, Value::String(s)) => validate_url(s),
+            (CheckKind::Length { min, max, equal }, Value::String(s)) => {
+                validate_length(s, *min, *max, *equal)
+            }
+            (CheckKind::Length { min, max, equal }, Value::Array(a)) => {
+                validate_length(a, *min, *max, *equal)
+            }
+            (CheckKind::Length { min, max, equal }, Value::Object(o)) => {
+                validate_length(o, *min, *max, *equal)
+            }
+            (CheckKind::Range { min, max }, Value::Number(n)) => match n.as_f64() {
+                Some(n) => validate_range(n, *min, *max),
+                None => false,
+            },
+            (CheckKind::Regex { regex, .. }, Value::String(s)) => regex.is_match(s),
+            (CheckKind::Contains(Value::String(needle)), Value::String(s)) => {
+                validate_contains(s.as_str(), needle.as_str())
+            }
+            (CheckKind::Contains(needle), Value::Array(a)) => {
+                validate_contains(a.as_slice(), needle)
+            }
+            (CheckKind::Contains(Value::String(needle)), Value::Object(o)) => {
+                o.contains_key(needle)
+            }
+            _ => false,
+        }
+    }
+
+    fn error(&self, value: &Value) -> ValidationError {
+        let mut err = new_error(self.default_code(), &self.message, &self.code, value);
+
+        match self.kind {
+            CheckKind::Length { min, max, equal } => {
+                if let Some(min) = min {
+                    err.add_param(Cow::from("min"), &min);
+                }
+                if let Some(max) = max {
+                    err.add_param(Cow::from("max"), &max);
+                }
+                if let Some(equal) = equal {
+                    err.add_param(Cow::from("equal"), &equal);
+                }
+            }
+            CheckKind::Range { min, max } => {
+                if let Some(min) = min {
+                    err.add_param(Cow::from("min"), &min);
+                }
+                if let Some(max) = max {
+                    err.add_param(Cow::from("max"), &max);
+                }
+            }
+            CheckKind::Regex { ref pattern, .. } => err.add_param(Cow::from("pattern"), pattern),
+            CheckKind::Contains(ref needle) => err.add_param(Cow::from("needle"), needle),
+            CheckKind::Email | CheckKind::Url => (),
+        }
+
+        err
+    }
+}
+
+/// `FieldRules` with the regexes compiled
+#[derive(Debug, Clone, Default)]
+struct CompiledRules {
+    /// The message and code of the `required` error, if the value is required
+    required: Option<(Option<String>, Option<String>)>,
+    checks: Vec<Check>,
+    fields: Vec<(String, CompiledRules)>,
+    items: Option<Box<CompiledRules>>,
+}
+
+impl CompiledRules {
+    fn compile(rules: FieldRules, path: &str) -> Result<CompiledRules, RuleSetError> {
+        let required = flag_customization(rules.required);
+
+        let mut checks = vec![];
+        checks.extend(Check::flag(CheckKind::Email, rules.email));
+        checks.extend(Check::flag(CheckKind::Url, rules.url));
+        if let Some(LengthRule { min, max, equal, message, code }) = rules.length {
+            checks.push(Check { kind: CheckKind::Length { min, max, equal }, message, code });
+        }
+        if let Some(RangeRule { min, max, message, code }) = rules.range {
+            checks.push(Check { kind: CheckKind::Range { min, max }, message, code });
+        }
+        if let Some(rule) = rules.regex {
+            let (pattern, message, code) = match rule {
+                RegexRule::Pattern(pattern) => (pattern, None, None),
+                RegexRule::Custom { pattern, message, code } => (pattern, message, code),
+            };
+            let regex = Regex::new(&pattern).map_err(|e| RuleSetError::InvalidRegex {
+                path: path.to_string(),
+                error: e.to_string(),
+            })?;
+            checks.push(Check { kind: CheckKind::Regex { regex, pattern }, message, code });
+        }
+        if let Some(rule) = rules.contains {
+            let (needle, message, code) = match rule {
+                ContainsRule::Needle(needle) => (needle, None, None),
+                ContainsRule::Custom { needle, message, code } => (needle, message, code),
+            };
+            checks.push(Check { kind: CheckKind::Contains(needle), message, code });
+        }
+
+        let mut fields = vec![];
+        for (key, rules) in rules.fields {
+            let field_path = join_path(path, &key);
+            fields.push((key, CompiledRules::compile(rules, &field_path)?));
+        }
+
+        let items = match rules.items {
+            Some(items) => Some(Box::new(CompiledRules::compile(*items, &format!("{}[]", path))?)),
+            None => None,
+        };
+
+        Ok(CompiledRules { required, checks, fields, items })
+    }
+
+    /// Adds the errors of the value itself to `errors` under `field`, returning whether there were any.
+    /// A missing or `null` value is only an error if it is required.
+    fn add_check_errors(
+        &self,
+        errors: &mut ValidationErrors,
+        field: &str,
+        value: Option<&Value>,
+    ) -> bool {
+        let value = match value {
+            Some(Value::Null) | None => {
+                if let Some((ref message, ref code)) = self.required {
+                    errors
+                        .add(field.to_string(), new_error("required", message, code, &Value::Null));
+                    return true;
+                }
+                return false;
+            }
+            Some(value) => value,
+        };
+
+        let mut failed = false;
+        for check in &self.checks {
+            if !check.is_valid(value) {
+                errors.add(field.to_string(), check.error(value));
+                failed = true;
+            }
+        }
+        failed
+    }
+
+    /// The errors of the nested object or array, if the value is one and there are rules for it
+    fn nested_errors(&self, value: &Value) -> Option<ValidationErrorsKind> {
+        match value {
+            Value::Object(_) if !self.fields.is_empty() => {
+                let errors = validate_fields(&self.fields, value);
+                if errors.is_empty() {
+                    None
+                } else {
+                    Some(ValidationErrorsKind::Struct(Box::new(errors)))
+                }
+            }
+            Value::Array(items) => {
+                let rules = self.items.as_ref()?;
+                let list = items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| (i, rules.validate_item(item)))
+                    .filter(|(_, errors)| !errors.is_empty())
+                    .map(|(i, errors)| (i, Box::new(errors)))
+                    .collect::<BTreeMap<_, _>>();
+                if list.is_empty() {
+                    None
+                } else {
+                    Some(ValidationErrorsKind::List(list))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn validate_field(&self, errors: &mut ValidationErrors, field: &str, value: Option<&Value>) {
+        // Like the derive, nested values are only validated if the value itself is valid
+        if self.add_check_errors(errors, field, value) {
+            return;
+        }
+        if let Some(kind) = value.and_then(|v| self.nested_errors(v)) {
+            errors.errors_mut().insert(Cow::from(field.to_string()), kind);
+        }
+    }
+
+    /// The errors of an array element: the errors of the element itself are under `__all__`
+    /// and the ones of an object are at the top level, like a list of nested structs
+    fn validate_item(&self, item: &Value) -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        if self.add_check_errors(&mut errors, "__all__", Some(item)) {
+            return errors;
+        }
+        match self.nested_errors(item) {
+            Some(ValidationErrorsKind::Struct(nested)) => *nested,
+            Some(kind) => {
+                errors.errors_mut().insert(Cow::from("__all__"), kind);
+                errors
+            }
+            None => errors,
+        }
+    }
+}
+
+fn validate_fields(fields: &[(String, CompiledRules)], value: &Value) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    let object = value.as_object();
+    for (field, rules) in fields {
+        rules.validate_field(&mut errors, field, object.and_then(|o| o.get(field)));
+    }
+    errors
+}
+
+/// Rules to validate a `serde_json::Value` at runtime, without a Rust type to derive `Validate` on.
+///
+/// It is a map from paths to `FieldRules`, where a path is made of `.` separated object keys each
+/// optionally followed by `[]` to validate every element of an array, eg `contacts[].email`.
+/// It can be deserialized from any format supported by serde (JSON, YAML, TOML...) and the regexes
+/// are compiled when it is built.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "IndexMap<String, FieldRules>")]
+pub struct RuleSet {
+    fields: Vec<(String, CompiledRules)>,
+}
+
+impl RuleSet {
+    /// Builds the rule set from a map of paths to rules
+    pub fn new(paths: IndexMap<String, FieldRules>) -> Result<RuleSet, RuleSetError> {
+        let mut root = FieldRules::default();
+        for (path, rules) in paths {
+            let segments = parse_path(&path)?;
+            let nested = segments.iter().rev().fold(rules, |rules, segment| match segment {
+                Segment::Key(key) => FieldRules {
+                    fields: vec![(key.to_string(), rules)].into_iter().collect(),
+                    ..Default::default()
+                },
+                Segment::Items => FieldRules { items: Some(Box::new(rules)), ..Default::default() },
+            });
+            // the merged paths are relative to the root which doesn't have a name
+            root.merge(nested, "")?;
+        }
+
+        let mut fields = vec![];
+        for (key, rules) in root.fields {
+            let compiled = CompiledRules::compile(rules, &key)?;
+            fields.push((key, compiled));
+        }
+        Ok(RuleSet { fields })
+    }
+
+    /// Parses the rule set from a JSON string
+    pub fn from_json(json: &str) -> Result<RuleSet, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Validates the value the same way the derive would: errors on objects are `Struct`,
+    /// errors on arrays are `List` and a value that is not an object is missing all its fields
+    pub fn validate(&self, value: &Value) -> Result<(), ValidationErrors> {
+        let errors = validate_fields(&self.fields, value);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl TryFrom<IndexMap<String, FieldRules>> for RuleSet {
+    type Error = RuleSetError;
+
+    fn try_from(paths: IndexMap<String, FieldRules>) -> Result<RuleSet, RuleSetError> {
+        RuleSet::new(paths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{RuleSet, RuleSetError};
+    use crate::ValidationErrorsKind;
+
+    const RULES: &str = r#"{
+        "email": { "required": true, "email": true },
+        "age": { "range": { "min": 18, "max": 120 } },
+        "name": { "length": { "min": 1, "max": 10, "message": "too long" } },
+        "code": { "regex": "^[A-Z]{3}$" },
+        "tags": { "contains": "main", "items": { "length": { "max": 5 } } },
+        "address": { "fields": { "zip": { "required": true } } },
+        "address.city": { "required": { "code": "no_city" } },
+        "contacts[].email": { "email": true }
+    }"#;
+
+    #[test]
+    fn test_valid_value() {
+        let rules = RuleSet::from_json(RULES).unwrap();
+        let value = json!({
+            "email": "bob@bob.com",
+            "age": 30,
+            "name": "Bob",
+            "code": "ABC",
+            "tags": ["main", "other"],
+            "address": { "zip": "12345", "city": "Paris" },
+            "contacts": [{ "email": "alice@bob.com" }, { "phone": "123" }],
+        });
+        assert!(rules.validate(&value).is_ok());
+    }
+
+    #[test]
+    fn test_optional_fields_can_be_missing() {
+        let rules = RuleSet::from_json(RULES).unwrap();
+        let value = json!({ "email": "bob@bob.com", "age": null });
+        assert!(rules.validate(&value).is_ok());
+    }
+
+    #[test]
+    fn test_field_errors() {
+        let rules = RuleSet::from_json(RULES).unwrap();
+        let value = json!({
+            "age": 12,
+            "name": "Bobbybobbybob",
+            "code": 123,
+            "tags": ["other"],
+        });
+        let errors = rules.validate(&value).unwrap_err();
+        let errs = errors.field_errors();
+        assert_eq!(errs.len(), 5);
+        assert_eq!(errs["email"][0].code, "required");
+        assert_eq!(errs["email"][0].params["value"], json!(null));
+        assert_eq!(errs["age"][0].code, "range");
+        assert_eq!(errs["age"][0].params["min"], json!(18.0));
+        assert_eq!(errs["age"][0].params["value"], json!(12));
+        assert_eq!(errs["name"][0].code, "length");
+        assert_eq!(errs["name"][0].clone().message.unwrap(), "too long");
+        assert_eq!(errs["name"][0].params["max"], json!(10));
+        // a value of the wrong type is invalid
+        assert_eq!(errs["code"][0].code, "regex");
+        assert_eq!(errs["code"][0].params["pattern"], json!("^[A-Z]{3}$"));
+        assert_eq!(errs["tags"][0].code, "contains");
+        assert_eq!(errs["tags"][0].params["needle"], json!("main"));
+    }
+
+    #[test]
+    fn test_nested_errors() {
+        let rules = RuleSet::from_json(RULES).unwrap();
+        let value = json!({
+            "email": "bob@bob.com",
+            "tags": ["main", "too long"],
+            "address": {},
+            "contacts": [{ "email": "alice@bob.com" }, { "email": "nope" }],
+        });
+        let errors = rules.validate(&value).unwrap_err();
+        let errors = errors.errors();
+        assert_eq!(errors.len(), 3);
+
+        match errors["address"] {
+            ValidationErrorsKind::Struct(ref address) => {
+                let errs = address.field_errors();
+                assert_eq!(errs["zip"][0].code, "required");
+                assert_eq!(errs["city"][0].code, "no_city");
+            }
+            ref kind => panic!("Expected a struct, got {:?}", kind),
+        }
+
+        match errors["contacts"] {
+            ValidationErrorsKind::List(ref list) => {
+                assert_eq!(list.len(), 1);
+                assert_eq!(list[&1].field_errors()["email"][0].code, "email");
+            }
+            ref kind => panic!("Expected a list, got {:?}", kind),
+        }
+
+        // errors of the items themselves are under `__all__`
+        match errors["tags"] {
+            ValidationErrorsKind::List(ref list) => {
+                assert_eq!(list.len(), 1);
+                assert_eq!(list[&1].field_errors()["__all__"][0].code, "length");
+            }
+            ref kind => panic!("Expected a list, got {:?}", kind),
+        }
+    }
+
+    #[test]
+    fn test_nested_values_are_skipped_on_errors() {
+        let rules = RuleSet::from_json(
+            r#"{ "tags": { "length": { "max": 1 }, "items": { "length": { "max": 1 } } } }"#,
+        )
+        .unwrap();
+        let errors = rules.validate(&json!({ "tags": ["ab", "cd"] })).unwrap_err();
+        assert_eq!(errors.field_errors()["tags"][0].code, "length");
+    }
+
+    #[test]
+    fn test_contains_on_objects_and_arrays() {
+        let rules = RuleSet::from_json(
+            r#"{ "ids": { "contains": 1 }, "map": { "contains": { "needle": "key", "code": "no_key" } } }"#,
+        )
+        .unwrap();
+        assert!(rules.validate(&json!({ "ids": [1, 2], "map": { "key": 0 } })).is_ok());
+        let errors = rules.validate(&json!({ "ids": [2], "map": { "other": 0 } })).unwrap_err();
+        let errs = errors.field_errors();
+        assert_eq!(errs["ids"][0].code, "contains");
+        assert_eq!(errs["map"][0].code, "no_key");
+    }
+
+    #[test]
+    fn test_contains_object_needles() {
+        // an object with other keys than the customization is the needle itself
+        let rules =
+            RuleSet::from_json(r#"{ "items": { "contains": { "needle": 1, "id": 2 } } }"#).unwrap();
+        assert!(rules.validate(&json!({ "items": [{ "needle": 1, "id": 2 }] })).is_ok());
+
+        // otherwise it has to be wrapped in `needle`
+        let rules = RuleSet::from_json(
+            r#"{ "items": { "contains": { "needle": { "needle": 1 }, "code": "missing" } } }"#,
+        )
+        .unwrap();
+        assert!(rules.validate(&json!({ "items": [{ "needle": 1 }] })).is_ok());
+        let errors = rules.validate(&json!({ "items": [1] })).unwrap_err();
+        assert_eq!(errors.field_errors()["items"][0].code, "missing");
+    }
+
+    #[test]
+    fn test_invalid_rule_sets() {
+        let err = RuleSet::from_json(r#"{ "a..b": { "required": true } }"#).unwrap_err();
+        assert!(err.to_string().contains("invalid path `a..b`"));
+
+        let err = RuleSet::from_json(r#"{ "code": { "regex": "[" } }"#).unwrap_err();
+        assert!(err.to_string().contains("invalid regex for `code`"));
+
+        let err = RuleSet::from_json(
+            r#"{ "a": { "fields": { "b": { "email": true } } }, "a.b": { "email": true } }"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("`email` is defined more than once for `a.b`"));
+
+        let err = RuleSet::from_json(r#"{ "a": { "emial": true } }"#).unwrap_err();
+        assert!(err.to_string().contains("unknown field `emial`"));
+
+        assert!(RuleSet::from_json(r#"{ "a": { "required": { "mesage": "x" } } }"#).is_err());
+        assert!(
+            RuleSet::from_json(r#"{ "a": { "regex": { "pattern": "a", "cde": "x" } } }"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_rule_set_error_display() {
+        let err =
+            RuleSetError::InvalidRegex { path: "a[].b".to_string(), error: "oops".to_string() };
+        assert_eq!(err.to_string(), "invalid regex for `a[].b`: oops");
+    }
+}
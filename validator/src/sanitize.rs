@@ -0,0 +1,290 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::hash::BuildHasher;
+
+use indexmap::IndexMap;
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+use crate::traits::Validate;
+use crate::types::ValidationErrors;
+
+/// This trait will be implemented by deriving `Sanitize`: it normalizes the value in place,
+/// typically before validating it.
+///
+/// It is implemented on options, boxes and collections of types implementing it so nested
+/// structs can be sanitized wherever they are.
+pub trait Sanitize {
+    fn sanitize(&mut self);
+
+    /// Sanitizes the value and validates the result
+    fn sanitize_and_validate(&mut self) -> Result<(), ValidationErrors>
+    where
+        Self: Validate,
+    {
+        self.sanitize();
+        self.validate()
+    }
+}
+
+impl<T: Sanitize + ?Sized> Sanitize for Box<T> {
+    fn sanitize(&mut self) {
+        T::sanitize(self)
+    }
+}
+
+impl<T: Sanitize> Sanitize for Option<T> {
+    fn sanitize(&mut self) {
+        if let Some(val) = self {
+            val.sanitize();
+        }
+    }
+}
+
+impl<T: Sanitize> Sanitize for [T] {
+    fn sanitize(&mut self) {
+        self.iter_mut().for_each(T::sanitize);
+    }
+}
+
+impl<T: Sanitize, const N: usize> Sanitize for [T; N] {
+    fn sanitize(&mut self) {
+        self.iter_mut().for_each(T::sanitize);
+    }
+}
+
+impl<T: Sanitize> Sanitize for Vec<T> {
+    fn sanitize(&mut self) {
+        self.iter_mut().for_each(T::sanitize);
+    }
+}
+
+impl<T: Sanitize> Sanitize for VecDeque<T> {
+    fn sanitize(&mut self) {
+        self.iter_mut().for_each(T::sanitize);
+    }
+}
+
+impl<K, V: Sanitize, S: BuildHasher> Sanitize for HashMap<K, V, S> {
+    fn sanitize(&mut self) {
+        self.values_mut().for_each(V::sanitize);
+    }
+}
+
+impl<K, V: Sanitize> Sanitize for BTreeMap<K, V> {
+    fn sanitize(&mut self) {
+        self.values_mut().for_each(V::sanitize);
+    }
+}
+
+impl<K, V: Sanitize, S> Sanitize for IndexMap<K, V, S> {
+    fn sanitize(&mut self) {
+        self.values_mut().for_each(V::sanitize);
+    }
+}
+
+/// Types holding strings that can be modified by the `#[normalize(..)]` operations: strings and
+/// options, boxes and collections of them. Only the values of maps are modified since changing
+/// the keys could create duplicates.
+pub trait StringsMut {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F);
+}
+
+impl StringsMut for String {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        f(self)
+    }
+}
+
+impl<'a> StringsMut for Cow<'a, str> {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        f(self.to_mut())
+    }
+}
+
+impl<T: StringsMut + ?Sized> StringsMut for Box<T> {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        T::for_each_string_mut(self, f)
+    }
+}
+
+impl<T: StringsMut> StringsMut for Option<T> {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        if let Some(val) = self {
+            val.for_each_string_mut(f);
+        }
+    }
+}
+
+impl<T: StringsMut> StringsMut for [T] {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        self.iter_mut().for_each(|val| val.for_each_string_mut(f));
+    }
+}
+
+impl<T: StringsMut, const N: usize> StringsMut for [T; N] {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        self.iter_mut().for_each(|val| val.for_each_string_mut(f));
+    }
+}
+
+impl<T: StringsMut> StringsMut for Vec<T> {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        self.iter_mut().for_each(|val| val.for_each_string_mut(f));
+    }
+}
+
+impl<T: StringsMut> StringsMut for VecDeque<T> {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        self.iter_mut().for_each(|val| val.for_each_string_mut(f));
+    }
+}
+
+impl<K, V: StringsMut, S: BuildHasher> StringsMut for HashMap<K, V, S> {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        self.values_mut().for_each(|val| val.for_each_string_mut(f));
+    }
+}
+
+impl<K, V: StringsMut> StringsMut for BTreeMap<K, V> {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        self.values_mut().for_each(|val| val.for_each_string_mut(f));
+    }
+}
+
+impl<K, V: StringsMut, S> StringsMut for IndexMap<K, V, S> {
+    fn for_each_string_mut<F: FnMut(&mut String)>(&mut self, f: &mut F) {
+        self.values_mut().for_each(|val| val.for_each_string_mut(f));
+    }
+}
+
+/// Removes the leading and trailing whitespace
+pub fn sanitize_trim(val: &mut String) {
+    let trimmed = val.trim();
+    if trimmed.len() != val.len() {
+        *val = trimmed.to_string();
+    }
+}
+
+/// Converts the string to lowercase, as defined by the Unicode `Lowercase` property
+pub fn sanitize_lowercase(val: &mut String) {
+    if val.chars().any(char::is_uppercase) {
+        *val = val.to_lowercase();
+    }
+}
+
+/// Converts the string to uppercase, as defined by the Unicode `Uppercase` property
+pub fn sanitize_uppercase(val: &mut String) {
+    if val.chars().any(char::is_lowercase) {
+        *val = val.to_uppercase();
+    }
+}
+
+/// Converts the string to the Unicode Normalization Form C, where characters are composed,
+/// eg `e` followed by a combining acute accent becomes `é`
+pub fn sanitize_nfc(val: &mut String) {
+    if !is_nfc(val) {
+        *val = val.nfc().collect();
+    }
+}
+
+/// Replaces every run of whitespace by a single space, without trimming the string
+pub fn sanitize_collapse_whitespace(val: &mut String) {
+    let mut collapsed = String::with_capacity(val.len());
+    let mut previous_is_whitespace = false;
+
+    for c in val.chars() {
+        if c.is_whitespace() {
+            if !previous_is_whitespace {
+                collapsed.push(' ');
+            }
+            previous_is_whitespace = true;
+        } else {
+            collapsed.push(c);
+            previous_is_whitespace = false;
+        }
+    }
+
+    if collapsed != *val {
+        *val = collapsed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_sanitize_trim() {
+        let mut val = String::from("\u{3000} hello world\n");
+        sanitize_trim(&mut val);
+        assert_eq!(val, "hello world");
+    }
+
+    #[test]
+    fn test_sanitize_case() {
+        let mut val = String::from("Bob@Example.COM");
+        sanitize_lowercase(&mut val);
+        assert_eq!(val, "bob@example.com");
+        let mut val = String::from("ÉTÉ");
+        sanitize_lowercase(&mut val);
+        assert_eq!(val, "été");
+        sanitize_uppercase(&mut val);
+        assert_eq!(val, "ÉTÉ");
+    }
+
+    #[test]
+    fn test_sanitize_nfc() {
+        let mut val = String::from("e\u{301}te\u{301}");
+        sanitize_nfc(&mut val);
+        assert_eq!(val, "\u{e9}t\u{e9}");
+        assert_eq!(val.chars().count(), 3);
+    }
+
+    #[test]
+    fn test_sanitize_collapse_whitespace() {
+        let mut val = String::from("  hello \t\n world  ");
+        sanitize_collapse_whitespace(&mut val);
+        assert_eq!(val, " hello world ");
+        let mut val = String::from("hello");
+        sanitize_collapse_whitespace(&mut val);
+        assert_eq!(val, "hello");
+    }
+
+    #[test]
+    fn test_for_each_string_mut() {
+        let mut val = Some(vec![String::from(" a "), String::from("b ")]);
+        val.for_each_string_mut(&mut sanitize_trim);
+        assert_eq!(val, Some(vec![String::from("a"), String::from("b")]));
+
+        let mut map = HashMap::new();
+        map.insert(" key ", String::from(" value "));
+        map.for_each_string_mut(&mut sanitize_trim);
+        assert_eq!(map[" key "], "value");
+
+        let mut val: Cow<'static, str> = Cow::Borrowed(" a ");
+        val.for_each_string_mut(&mut sanitize_trim);
+        assert_eq!(val, "a");
+    }
+
+    #[test]
+    fn test_sanitize_collections() {
+        struct Name(String);
+
+        impl Sanitize for Name {
+            fn sanitize(&mut self) {
+                sanitize_trim(&mut self.0);
+            }
+        }
+
+        let mut names = vec![Some(Name(String::from(" a "))), None];
+        names.sanitize();
+        assert_eq!(names[0].as_ref().unwrap().0, "a");
+
+        let mut names = Box::new([Name(String::from(" b "))]);
+        names.sanitize();
+        assert_eq!(names[0].0, "b");
+    }
+}
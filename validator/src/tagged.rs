@@ -0,0 +1,100 @@
+//! A lossless representation of `ValidationErrors` where every kind is tagged, to be used with
+//! `#[serde(with = "validator::tagged")]`:
+//!
+//! ```json
+//! {"address": {"struct": {"zip": {"field": [{"code": "length", ...}]}}}}
+//! ```
+//!
+//! The default representation is untagged, which is what most clients expect, but it can't tell
+//! a list from nested structs whose field names are all numbers.
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use indexmap::IndexMap;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::types::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum TaggedKind {
+    Struct(TaggedErrors),
+    List(BTreeMap<usize, TaggedErrors>),
+    Field(Vec<ValidationError>),
+}
+
+#[derive(Deserialize)]
+struct TaggedErrors(IndexMap<Cow<'static, str>, TaggedKind>);
+
+/// Borrows the errors to serialize them in the same shape as `TaggedErrors`
+struct TaggedErrorsRef<'a>(&'a IndexMap<Cow<'static, str>, ValidationErrorsKind>);
+
+#[derive(Serialize)]
+#[serde(rename = "TaggedKind", rename_all = "snake_case")]
+enum TaggedKindRef<'a> {
+    Struct(TaggedErrorsRef<'a>),
+    List(TaggedListRef<'a>),
+    Field(&'a [ValidationError]),
+}
+
+struct TaggedListRef<'a>(&'a BTreeMap<usize, Box<ValidationErrors>>);
+
+impl<'a> From<&'a ValidationErrorsKind> for TaggedKindRef<'a> {
+    fn from(kind: &'a ValidationErrorsKind) -> TaggedKindRef<'a> {
+        match kind {
+            ValidationErrorsKind::Struct(errors) => {
+                TaggedKindRef::Struct(TaggedErrorsRef(errors.errors()))
+            }
+            ValidationErrorsKind::List(list) => TaggedKindRef::List(TaggedListRef(list)),
+            ValidationErrorsKind::Field(errors) => TaggedKindRef::Field(errors),
+        }
+    }
+}
+
+impl<'a> Serialize for TaggedErrorsRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer
+            .collect_map(self.0.iter().map(|(field, kind)| (field, TaggedKindRef::from(kind))))
+    }
+}
+
+impl<'a> Serialize for TaggedListRef<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer
+            .collect_map(self.0.iter().map(|(i, errors)| (i, TaggedErrorsRef(errors.errors()))))
+    }
+}
+
+impl From<TaggedErrors> for ValidationErrors {
+    fn from(tagged: TaggedErrors) -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        for (field, kind) in tagged.0 {
+            let kind = match kind {
+                TaggedKind::Struct(errors) => ValidationErrorsKind::Struct(Box::new(errors.into())),
+                TaggedKind::List(list) => ValidationErrorsKind::List(
+                    list.into_iter().map(|(i, errors)| (i, Box::new(errors.into()))).collect(),
+                ),
+                TaggedKind::Field(errors) => ValidationErrorsKind::Field(errors),
+            };
+            errors.errors_mut().insert(field, kind);
+        }
+        errors
+    }
+}
+
+/// Serializes the errors with tagged kinds
+pub fn serialize<S: Serializer>(
+    errors: &ValidationErrors,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    TaggedErrorsRef(errors.errors()).serialize(serializer)
+}
+
+/// Deserializes errors serialized with tagged kinds
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ValidationErrors, D::Error> {
+    TaggedErrors::deserialize(deserializer).map(ValidationErrors::from)
+}
@@ -1,10 +1,15 @@
-use std::borrow::Cow;
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::borrow::{Borrow, Cow};
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::rc::Rc;
+use std::sync::Arc;
 
-#[cfg(feature = "indexmap")]
 use indexmap::{IndexMap, IndexSet};
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::types::ValidationErrors;
+use crate::types::{ValidationError, ValidationErrors, ValidationErrorsKind};
+use crate::validation::length::LengthMode;
 
 /// Trait to implement if one wants to make the `length` validator
 /// work for more types
@@ -12,29 +17,91 @@ use crate::types::ValidationErrors;
 /// A bit sad it's not there by default in Rust
 pub trait HasLen {
     fn length(&self) -> u64;
+
+    /// The length measured in the given unit. Only strings have several ways to be measured
+    /// so by default the mode is ignored and this is the same as `length`.
+    fn length_with_mode(&self, _mode: LengthMode) -> u64 {
+        self.length()
+    }
+}
+
+fn str_length(s: &str, mode: LengthMode) -> u64 {
+    let len = match mode {
+        LengthMode::Bytes => s.len(),
+        LengthMode::Chars => s.chars().count(),
+        LengthMode::Graphemes => s.graphemes(true).count(),
+        LengthMode::Utf16 => s.encode_utf16().count(),
+    };
+    len as u64
 }
 
 impl HasLen for String {
     fn length(&self) -> u64 {
-        self.chars().count() as u64
+        str_length(self, LengthMode::Chars)
+    }
+
+    fn length_with_mode(&self, mode: LengthMode) -> u64 {
+        str_length(self, mode)
     }
 }
 
-impl<'a> HasLen for &'a String {
+impl HasLen for &String {
     fn length(&self) -> u64 {
-        self.chars().count() as u64
+        str_length(self, LengthMode::Chars)
+    }
+
+    fn length_with_mode(&self, mode: LengthMode) -> u64 {
+        str_length(self, mode)
     }
 }
 
-impl<'a> HasLen for &'a str {
+impl HasLen for &str {
     fn length(&self) -> u64 {
-        self.chars().count() as u64
+        str_length(self, LengthMode::Chars)
+    }
+
+    fn length_with_mode(&self, mode: LengthMode) -> u64 {
+        str_length(self, mode)
     }
 }
 
 impl<'a> HasLen for Cow<'a, str> {
     fn length(&self) -> u64 {
-        self.len() as u64
+        str_length(self, LengthMode::Chars)
+    }
+
+    fn length_with_mode(&self, mode: LengthMode) -> u64 {
+        str_length(self, mode)
+    }
+}
+
+impl<'a> HasLen for &Cow<'a, str> {
+    fn length(&self) -> u64 {
+        str_length(self, LengthMode::Chars)
+    }
+
+    fn length_with_mode(&self, mode: LengthMode) -> u64 {
+        str_length(self, mode)
+    }
+}
+
+impl HasLen for Box<str> {
+    fn length(&self) -> u64 {
+        str_length(self, LengthMode::Chars)
+    }
+
+    fn length_with_mode(&self, mode: LengthMode) -> u64 {
+        str_length(self, mode)
+    }
+}
+
+impl HasLen for &Box<str> {
+    fn length(&self) -> u64 {
+        str_length(self, LengthMode::Chars)
+    }
+
+    fn length_with_mode(&self, mode: LengthMode) -> u64 {
+        str_length(self, mode)
     }
 }
 
@@ -44,7 +111,7 @@ impl<T> HasLen for Vec<T> {
     }
 }
 
-impl<'a, T> HasLen for &'a Vec<T> {
+impl<T> HasLen for &Vec<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -68,7 +135,7 @@ impl<T, const N: usize> HasLen for &[T; N] {
     }
 }
 
-impl<'a, K, V, S> HasLen for &'a HashMap<K, V, S> {
+impl<K, V, S> HasLen for &HashMap<K, V, S> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -80,7 +147,7 @@ impl<K, V, S> HasLen for HashMap<K, V, S> {
     }
 }
 
-impl<'a, T, S> HasLen for &'a HashSet<T, S> {
+impl<T, S> HasLen for &HashSet<T, S> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -92,7 +159,7 @@ impl<T, S> HasLen for HashSet<T, S> {
     }
 }
 
-impl<'a, K, V> HasLen for &'a BTreeMap<K, V> {
+impl<K, V> HasLen for &BTreeMap<K, V> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -104,7 +171,7 @@ impl<K, V> HasLen for BTreeMap<K, V> {
     }
 }
 
-impl<'a, T> HasLen for &'a BTreeSet<T> {
+impl<T> HasLen for &BTreeSet<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -116,88 +183,255 @@ impl<T> HasLen for BTreeSet<T> {
     }
 }
 
-#[cfg(feature = "indexmap")]
-impl<'a, K, V> HasLen for &'a IndexMap<K, V> {
+impl<K, V> HasLen for &IndexMap<K, V> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
 }
 
-#[cfg(feature = "indexmap")]
 impl<K, V> HasLen for IndexMap<K, V> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
 }
 
-#[cfg(feature = "indexmap")]
-impl<'a, T> HasLen for &'a IndexSet<T> {
+impl<T> HasLen for &IndexSet<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
 }
 
-#[cfg(feature = "indexmap")]
 impl<T> HasLen for IndexSet<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
 }
 
+impl HasLen for &serde_json::Map<String, serde_json::Value> {
+    fn length(&self) -> u64 {
+        self.len() as u64
+    }
+}
+
+impl HasLen for serde_json::Map<String, serde_json::Value> {
+    fn length(&self) -> u64 {
+        self.len() as u64
+    }
+}
+
 /// Trait to implement if one wants to make the `contains` validator
 /// work for more types
-pub trait Contains {
+///
+/// `N` is the type of the needle: strings look for a substring, lists for an element
+/// equal to the needle and sets and maps for a key.
+pub trait Contains<N: ?Sized = str> {
     #[must_use]
-    fn has_element(&self, needle: &str) -> bool;
+    fn has_element(&self, needle: &N) -> bool;
 }
 
-impl Contains for String {
-    fn has_element(&self, needle: &str) -> bool {
-        self.contains(needle)
+impl<T: ?Sized + Contains<N>, N: ?Sized> Contains<N> for &T {
+    fn has_element(&self, needle: &N) -> bool {
+        T::has_element(*self, needle)
     }
 }
 
-impl<'a> Contains for &'a String {
-    fn has_element(&self, needle: &str) -> bool {
-        self.contains(needle)
+impl<N: ?Sized + AsRef<str>> Contains<N> for String {
+    fn has_element(&self, needle: &N) -> bool {
+        self.contains(needle.as_ref())
+    }
+}
+
+impl<N: ?Sized + AsRef<str>> Contains<N> for str {
+    fn has_element(&self, needle: &N) -> bool {
+        self.contains(needle.as_ref())
+    }
+}
+
+impl<'a, N: ?Sized + AsRef<str>> Contains<N> for Cow<'a, str> {
+    fn has_element(&self, needle: &N) -> bool {
+        self.contains(needle.as_ref())
+    }
+}
+
+impl<T: PartialEq<N>, N: ?Sized> Contains<N> for [T] {
+    fn has_element(&self, needle: &N) -> bool {
+        self.iter().any(|e| e == needle)
+    }
+}
+
+impl<T: PartialEq<N>, N: ?Sized, const L: usize> Contains<N> for [T; L] {
+    fn has_element(&self, needle: &N) -> bool {
+        self.iter().any(|e| e == needle)
+    }
+}
+
+impl<T: PartialEq<N>, N: ?Sized> Contains<N> for Vec<T> {
+    fn has_element(&self, needle: &N) -> bool {
+        self.iter().any(|e| e == needle)
     }
 }
 
-impl<'a> Contains for &'a str {
-    fn has_element(&self, needle: &str) -> bool {
+impl<T, N, S> Contains<N> for HashSet<T, S>
+where
+    T: Borrow<N> + Eq + Hash,
+    N: ?Sized + Eq + Hash,
+    S: BuildHasher,
+{
+    fn has_element(&self, needle: &N) -> bool {
         self.contains(needle)
     }
 }
 
-impl<'a> Contains for Cow<'a, str> {
-    fn has_element(&self, needle: &str) -> bool {
+impl<T, N> Contains<N> for BTreeSet<T>
+where
+    T: Borrow<N> + Ord,
+    N: ?Sized + Ord,
+{
+    fn has_element(&self, needle: &N) -> bool {
         self.contains(needle)
     }
 }
 
-impl<S, H: ::std::hash::BuildHasher> Contains for HashMap<String, S, H> {
-    fn has_element(&self, needle: &str) -> bool {
+impl<K, V, N, S> Contains<N> for HashMap<K, V, S>
+where
+    K: Borrow<N> + Eq + Hash,
+    N: ?Sized + Eq + Hash,
+    S: BuildHasher,
+{
+    fn has_element(&self, needle: &N) -> bool {
         self.contains_key(needle)
     }
 }
 
-impl<'a, S, H: ::std::hash::BuildHasher> Contains for &'a HashMap<String, S, H> {
-    fn has_element(&self, needle: &str) -> bool {
+impl<K, V, N> Contains<N> for BTreeMap<K, V>
+where
+    K: Borrow<N> + Ord,
+    N: ?Sized + Ord,
+{
+    fn has_element(&self, needle: &N) -> bool {
         self.contains_key(needle)
     }
 }
 
+impl<T, N, S> Contains<N> for IndexSet<T, S>
+where
+    T: Borrow<N> + Eq + Hash,
+    N: ?Sized + Eq + Hash,
+    S: BuildHasher,
+{
+    fn has_element(&self, needle: &N) -> bool {
+        self.contains(needle)
+    }
+}
+
+impl<K, V, N, S> Contains<N> for IndexMap<K, V, S>
+where
+    K: Borrow<N> + Eq + Hash,
+    N: ?Sized + Eq + Hash,
+    S: BuildHasher,
+{
+    fn has_element(&self, needle: &N) -> bool {
+        self.contains_key(needle)
+    }
+}
+
+/// The errors a `custom` validation function can return: a `ValidationError`, several of them or
+/// the errors of a nested struct.
+///
+/// Returning `Err` always fails the validation: if there is no error in it, like with `Err(vec![])`,
+/// a `ValidationError` with the code of the `custom` validator is added to the field instead.
+pub trait CustomError {
+    /// Whether there is no error to add
+    fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Adds the errors to the ones of `field`, once transformed by `f`. Nested errors are merged
+    /// as the errors of a nested struct.
+    fn add_to<F, T>(self, errors: &mut ValidationErrors, field: F, f: T)
+    where
+        F: Into<Cow<'static, str>>,
+        T: FnMut(ValidationError) -> ValidationError;
+}
+
+impl CustomError for ValidationError {
+    fn add_to<F, T>(self, errors: &mut ValidationErrors, field: F, mut f: T)
+    where
+        F: Into<Cow<'static, str>>,
+        T: FnMut(ValidationError) -> ValidationError,
+    {
+        errors.add(field, f(self));
+    }
+}
+
+impl CustomError for Vec<ValidationError> {
+    fn is_empty(&self) -> bool {
+        Vec::is_empty(self)
+    }
+
+    fn add_to<F, T>(self, errors: &mut ValidationErrors, field: F, f: T)
+    where
+        F: Into<Cow<'static, str>>,
+        T: FnMut(ValidationError) -> ValidationError,
+    {
+        if !self.is_empty() {
+            errors.add_nested(
+                field.into(),
+                ValidationErrorsKind::Field(self.into_iter().map(f).collect()),
+            );
+        }
+    }
+}
+
+impl CustomError for ValidationErrors {
+    fn is_empty(&self) -> bool {
+        ValidationErrors::is_empty(self)
+    }
+
+    fn add_to<F, T>(self, errors: &mut ValidationErrors, field: F, mut f: T)
+    where
+        F: Into<Cow<'static, str>>,
+        T: FnMut(ValidationError) -> ValidationError,
+    {
+        if !self.is_empty() {
+            let nested = self.map_errors(|_, error| f(error));
+            errors.add_nested(field.into(), ValidationErrorsKind::Struct(Box::new(nested)));
+        }
+    }
+}
+
 /// This is the original trait that was implemented by deriving `Validate`. It will still be
 /// implemented for struct validations that don't take custom arguments. The call is being
 /// forwarded to the `ValidateArgs<'v_a>` trait.
 pub trait Validate {
+    /// Fails if there is any error that is not a warning, without the warnings
     fn validate(&self) -> Result<(), ValidationErrors>;
+
+    /// Returns the warnings when the validation succeeds, the errors along with the warnings
+    /// otherwise
+    fn validate_with_warnings(&self) -> Result<ValidationErrors, ValidationErrors> {
+        self.validate().map(|()| ValidationErrors::new())
+    }
 }
 
 impl<T: Validate> Validate for &T {
     fn validate(&self) -> Result<(), ValidationErrors> {
         T::validate(*self)
     }
+
+    fn validate_with_warnings(&self) -> Result<ValidationErrors, ValidationErrors> {
+        T::validate_with_warnings(*self)
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        T::validate(self)
+    }
+
+    fn validate_with_warnings(&self) -> Result<ValidationErrors, ValidationErrors> {
+        T::validate_with_warnings(self)
+    }
 }
 
 /// This trait will be implemented by deriving `Validate`. This implementation can take one
@@ -209,4 +443,344 @@ pub trait ValidateArgs<'v_a> {
     type Args;
 
     fn validate_args(&self, args: Self::Args) -> Result<(), ValidationErrors>;
+
+    /// Same as `Validate::validate_with_warnings` with arguments
+    fn validate_args_with_warnings(
+        &self,
+        args: Self::Args,
+    ) -> Result<ValidationErrors, ValidationErrors> {
+        self.validate_args(args).map(|()| ValidationErrors::new())
+    }
+}
+
+/// This trait will be implemented by deriving `Validate` on a struct with a
+/// `#[validate(context = "...")]` attribute. The context is given to every custom and schema
+/// function, its `ValidateArgs` implementation takes a reference to it.
+pub trait ValidateWith<C: ?Sized> {
+    fn validate_with(&self, context: &C) -> Result<(), ValidationErrors>;
+
+    /// Same as `Validate::validate_with_warnings` with the context
+    fn validate_with_context_and_warnings(
+        &self,
+        context: &C,
+    ) -> Result<ValidationErrors, ValidationErrors> {
+        self.validate_with(context).map(|()| ValidationErrors::new())
+    }
+}
+
+impl<'v_a, T: ValidateArgs<'v_a> + ?Sized> ValidateArgs<'v_a> for &T {
+    type Args = T::Args;
+
+    fn validate_args(&self, args: Self::Args) -> Result<(), ValidationErrors> {
+        T::validate_args(*self, args)
+    }
+
+    fn validate_args_with_warnings(
+        &self,
+        args: Self::Args,
+    ) -> Result<ValidationErrors, ValidationErrors> {
+        T::validate_args_with_warnings(*self, args)
+    }
+}
+
+impl<'v_a, T: ValidateArgs<'v_a> + ?Sized> ValidateArgs<'v_a> for Box<T> {
+    type Args = T::Args;
+
+    fn validate_args(&self, args: Self::Args) -> Result<(), ValidationErrors> {
+        T::validate_args(self, args)
+    }
+
+    fn validate_args_with_warnings(
+        &self,
+        args: Self::Args,
+    ) -> Result<ValidationErrors, ValidationErrors> {
+        T::validate_args_with_warnings(self, args)
+    }
+}
+
+/// The nested validation of a field marked with `#[validate(nested)]`. It is implemented for
+/// every `Validate` type, by the derive for the structs taking arguments and for the wrappers and
+/// collections of std and indexmap holding them, so they can be composed freely,
+/// e.g. `Option<Box<Vec<Arc<T>>>>`.
+///
+/// A hand-written `ValidateArgs` implementation taking arguments can implement it with
+/// `validate_nested_args`, for the type and for its `Box` if it is boxed.
+///
+/// A value validates to a `Struct` kind and a collection to a `List` kind keyed by the index of
+/// its items, in iteration order. Maps only validate their values.
+pub trait ValidateNested<'v_a> {
+    type Args;
+
+    /// Validates the value, returning both the errors and the warnings
+    fn validate_nested(&self, args: Self::Args) -> Result<(), ValidationErrorsKind>;
+
+    /// Same as `validate_nested` for the values of the field `site` which is only nested in
+    /// itself `max_depth` times, so deeply self-referential values can't overflow the stack. The
+    /// values beyond get a `max_depth` error instead. The limit doesn't apply to the other nested
+    /// fields of the values, the derive identifies the field with the path of the struct and its
+    /// name.
+    fn validate_nested_with_max_depth(
+        &self,
+        args: Self::Args,
+        site: &'static str,
+        max_depth: usize,
+    ) -> Result<(), ValidationErrorsKind> {
+        let _limit = DepthLimit::set(Some((site, max_depth)));
+        self.validate_nested(args)
+    }
+}
+
+thread_local! {
+    /// The field and limit of the values about to be validated, if any
+    static DEPTH_LIMIT: Cell<Option<(&'static str, usize)>> = Cell::new(None);
+    /// The fields with a limit whose values are being validated on this thread
+    static LIMITED_FIELDS: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+/// Sets the limit of the values about to be validated until dropped, restoring the previous one
+struct DepthLimit(Option<(&'static str, usize)>);
+
+impl DepthLimit {
+    fn set(limit: Option<(&'static str, usize)>) -> DepthLimit {
+        DepthLimit(DEPTH_LIMIT.with(|cell| cell.replace(limit)))
+    }
+}
+
+impl Drop for DepthLimit {
+    fn drop(&mut self) {
+        DEPTH_LIMIT.with(|cell| cell.set(self.0));
+    }
+}
+
+/// Counts a value of a field with a limit being validated until dropped
+struct Nesting;
+
+impl Nesting {
+    fn enter(site: &'static str) -> Nesting {
+        LIMITED_FIELDS.with(|fields| fields.borrow_mut().push(site));
+        Nesting
+    }
+}
+
+impl Drop for Nesting {
+    fn drop(&mut self) {
+        LIMITED_FIELDS.with(|fields| fields.borrow_mut().pop());
+    }
+}
+
+/// Validates a nested value with `validate`, which returns both the errors and the warnings,
+/// unless its field is already nested in itself as many times as its limit allows
+fn validate_nested_value<F>(validate: F) -> Result<(), ValidationErrorsKind>
+where
+    F: FnOnce() -> Result<ValidationErrors, ValidationErrors>,
+{
+    // The limit is only given to the values of the field, not to the ones nested in them
+    let limit = DepthLimit::set(None);
+    let _nesting = match limit.0 {
+        Some((site, max_depth)) => {
+            let depth = LIMITED_FIELDS
+                .with(|fields| fields.borrow().iter().filter(|field| **field == site).count());
+            if depth >= max_depth {
+                let mut err = ValidationError::new("max_depth");
+                err.add_param(Cow::from("max_depth"), &max_depth);
+                return Err(ValidationErrorsKind::Field(vec![err]));
+            }
+            Some(Nesting::enter(site))
+        }
+        None => None,
+    };
+
+    ValidationErrors::with_warnings(validate())
+        .map_err(|errors| ValidationErrorsKind::Struct(Box::new(errors)))
+}
+
+/// The `ValidateNested::validate_nested` of a `ValidateArgs` type, used by the derive for the
+/// structs taking arguments
+pub fn validate_nested_args<'v_a, T: ValidateArgs<'v_a> + ?Sized>(
+    value: &T,
+    args: T::Args,
+) -> Result<(), ValidationErrorsKind> {
+    validate_nested_value(|| value.validate_args_with_warnings(args))
+}
+
+impl<'v_a, T: Validate + ?Sized> ValidateNested<'v_a> for T {
+    type Args = ();
+
+    fn validate_nested(&self, _args: ()) -> Result<(), ValidationErrorsKind> {
+        validate_nested_value(|| self.validate_with_warnings())
+    }
 }
+
+impl<'v_a, T: ValidateNested<'v_a>> ValidateNested<'v_a> for Option<T> {
+    type Args = T::Args;
+
+    fn validate_nested(&self, args: Self::Args) -> Result<(), ValidationErrorsKind> {
+        match self {
+            Some(value) => value.validate_nested(args),
+            None => Ok(()),
+        }
+    }
+}
+
+macro_rules! impl_validate_nested_deref {
+    ($($ty:ident),*) => {
+        $(
+            impl<'v_a, T: ValidateNested<'v_a> + ?Sized> ValidateNested<'v_a> for $ty<T> {
+                type Args = T::Args;
+
+                fn validate_nested(&self, args: Self::Args) -> Result<(), ValidationErrorsKind> {
+                    T::validate_nested(self, args)
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_nested_deref!(Arc, Rc);
+
+/// The errors of an item of a collection, a list holding lists is turned into a struct keyed
+/// by the inner indices
+fn item_errors(kind: ValidationErrorsKind) -> Box<ValidationErrors> {
+    match kind {
+        ValidationErrorsKind::Struct(errors) => errors,
+        ValidationErrorsKind::List(list) => {
+            let mut errors = ValidationErrors::new();
+            for (index, item) in list {
+                errors.add_nested(Cow::from(index.to_string()), ValidationErrorsKind::Struct(item));
+            }
+            Box::new(errors)
+        }
+        kind @ ValidationErrorsKind::Field(_) => {
+            let mut errors = ValidationErrors::new();
+            errors.add_nested(Cow::from("__all__"), kind);
+            Box::new(errors)
+        }
+    }
+}
+
+fn validate_items<'v_a, 'i, T, I>(items: I, args: T::Args) -> Result<(), ValidationErrorsKind>
+where
+    T: ValidateNested<'v_a> + ?Sized + 'i,
+    T::Args: Clone,
+    I: IntoIterator<Item = &'i T>,
+{
+    let errors = items
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, item)| {
+            item.validate_nested(args.clone()).err().map(|kind| (i, item_errors(kind)))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationErrorsKind::List(errors))
+    }
+}
+
+impl<'v_a, T: ValidateNested<'v_a>> ValidateNested<'v_a> for [T]
+where
+    T::Args: Clone,
+{
+    type Args = T::Args;
+
+    fn validate_nested(&self, args: Self::Args) -> Result<(), ValidationErrorsKind> {
+        validate_items(self, args)
+    }
+}
+
+impl<'v_a, T: ValidateNested<'v_a>, const N: usize> ValidateNested<'v_a> for [T; N]
+where
+    T::Args: Clone,
+{
+    type Args = T::Args;
+
+    fn validate_nested(&self, args: Self::Args) -> Result<(), ValidationErrorsKind> {
+        validate_items(self, args)
+    }
+}
+
+macro_rules! impl_validate_nested_collection {
+    ($($ty:ident<T $(, $s:ident)?>),*) => {
+        $(
+            impl<'v_a, T: ValidateNested<'v_a> $(, $s)?> ValidateNested<'v_a> for $ty<T $(, $s)?>
+            where
+                T::Args: Clone,
+            {
+                type Args = T::Args;
+
+                fn validate_nested(&self, args: Self::Args) -> Result<(), ValidationErrorsKind> {
+                    validate_items(self, args)
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_nested_collection!(
+    Vec<T>,
+    VecDeque<T>,
+    LinkedList<T>,
+    BTreeSet<T>,
+    HashSet<T, S>,
+    IndexSet<T, S>
+);
+
+macro_rules! impl_validate_nested_map {
+    ($($ty:ident<K, V $(, $s:ident)?>),*) => {
+        $(
+            impl<'v_a, K, V $(, $s)?> ValidateNested<'v_a> for $ty<K, V $(, $s)?>
+            where
+                V: ValidateNested<'v_a>,
+                V::Args: Clone,
+            {
+                type Args = V::Args;
+
+                fn validate_nested(&self, args: Self::Args) -> Result<(), ValidationErrorsKind> {
+                    validate_items(self.values(), args)
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_nested_map!(BTreeMap<K, V>, HashMap<K, V, S>, IndexMap<K, V, S>);
+
+// A generic impl for `Box<T>` would conflict with the one of the `Validate` types since other
+// crates can implement `Validate` for a `Box`, so every wrapper and collection gets its own
+// forwarding impl once boxed. `Box<T>` itself forwards to `T` through `Validate`, or through the
+// impl the derive adds for the structs taking arguments.
+macro_rules! impl_validate_nested_boxed {
+    ($([$($params:tt)*] $ty:ty),*) => {
+        $(
+            impl<'v_a, $($params)*> ValidateNested<'v_a> for Box<$ty>
+            where
+                $ty: ValidateNested<'v_a>,
+            {
+                type Args = <$ty as ValidateNested<'v_a>>::Args;
+
+                fn validate_nested(&self, args: Self::Args) -> Result<(), ValidationErrorsKind> {
+                    <$ty as ValidateNested<'v_a>>::validate_nested(self, args)
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_nested_boxed!(
+    [T] Option<T>,
+    [T: ?Sized] Arc<T>,
+    [T: ?Sized] Rc<T>,
+    [T] [T],
+    [T, const N: usize] [T; N],
+    [T] Vec<T>,
+    [T] VecDeque<T>,
+    [T] LinkedList<T>,
+    [T] BTreeSet<T>,
+    [T, S] HashSet<T, S>,
+    [T, S] IndexSet<T, S>,
+    [K, V] BTreeMap<K, V>,
+    [K, V, S] HashMap<K, V, S>,
+    [K, V, S] IndexMap<K, V, S>
+);
@@ -1,23 +1,69 @@
 use std::borrow::Cow;
-use std::collections::{hash_map::Entry::Vacant, BTreeMap, HashMap};
+use std::collections::btree_map::Entry as BTreeEntry;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::mem;
 
+use indexmap::map::Entry::{Occupied, Vacant};
+use indexmap::IndexMap;
+use serde::de::{self, Deserialize, Deserializer};
 use serde::ser::Serialize;
 use serde_derive::{Deserialize, Serialize};
-use serde_json::{to_value, Value};
+use serde_json::{from_value, to_value, Value};
+
+/// Whether an error makes the validation fail or is only reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    /// Reported by `validate_with_warnings` but doesn't make the validation fail
+    Warning,
+}
+
+impl Default for Severity {
+    fn default() -> Severity {
+        Severity::Error
+    }
+}
+
+impl Severity {
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        *self == Severity::Error
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct ValidationError {
     pub code: Cow<'static, str>,
     pub message: Option<Cow<'static, str>>,
-    pub params: HashMap<Cow<'static, str>, Value>,
+    pub params: IndexMap<Cow<'static, str>, Value>,
+    /// Only serialized for warnings, so errors look the same as before severities existed
+    #[serde(default, skip_serializing_if = "Severity::is_error")]
+    pub severity: Severity,
 }
 
 impl ValidationError {
     pub fn new(code: &'static str) -> ValidationError {
-        ValidationError { code: Cow::from(code), message: None, params: HashMap::new() }
+        ValidationError {
+            code: Cow::from(code),
+            message: None,
+            params: IndexMap::new(),
+            severity: Severity::Error,
+        }
+    }
+
+    /// Creates an error with the `Warning` severity
+    pub fn warning(code: &'static str) -> ValidationError {
+        ValidationError { severity: Severity::Warning, ..ValidationError::new(code) }
+    }
+
+    #[must_use]
+    pub fn is_warning(&self) -> bool {
+        self.severity == Severity::Warning
     }
 
-    pub fn add_param<T: Serialize>(&mut self, name: Cow<'static, str>, val: &T) {
+    pub fn add_param<T: Serialize + ?Sized>(&mut self, name: Cow<'static, str>, val: &T) {
         self.params.insert(name, to_value(val).unwrap());
     }
 }
@@ -39,37 +85,76 @@ pub enum ValidationErrorsKind {
     Field(Vec<ValidationError>),
 }
 
-#[derive(Default, Debug, Serialize, Clone, PartialEq)]
-pub struct ValidationErrors(HashMap<&'static str, ValidationErrorsKind>);
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ValidationErrors(IndexMap<Cow<'static, str>, ValidationErrorsKind>);
+
+/// The serialization is untagged so the kind is found from the shape of the value: an array is
+/// `Field`, an object whose keys are all indices is `List` and any other object is `Struct`.
+/// A struct whose field names are all numbers and whose fields are all nested structs, which the
+/// derive never produces, is read as a list: see the `tagged` module for a lossless representation.
+impl<'de> Deserialize<'de> for ValidationErrorsKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let is_list = match value {
+            Value::Object(ref map) => {
+                !map.is_empty() && map.keys().all(|key| key.parse::<usize>().is_ok())
+            }
+            _ => false,
+        };
+
+        let kind = match value {
+            Value::Array(_) => from_value(value).map(ValidationErrorsKind::Field),
+            // a struct with numeric field names that are not all nested structs
+            Value::Object(_) if is_list => from_value(value.clone())
+                .map(ValidationErrorsKind::List)
+                .or_else(|_| from_value(value).map(ValidationErrorsKind::Struct)),
+            _ => from_value(value).map(ValidationErrorsKind::Struct),
+        };
+        kind.map_err(de::Error::custom)
+    }
+}
 
 impl ValidationErrors {
     pub fn new() -> ValidationErrors {
-        ValidationErrors(HashMap::new())
+        ValidationErrors(IndexMap::new())
     }
 
     /// Returns a boolean indicating whether a validation result includes validation errors for a
     /// given field. May be used as a condition for performing nested struct validations on a field
-    /// in the absence of field-level validation errors.
+    /// in the absence of field-level validation errors. Warnings are not counted.
     #[must_use]
-    pub fn has_error(result: &Result<(), ValidationErrors>, field: &'static str) -> bool {
+    pub fn has_error(result: &Result<(), ValidationErrors>, field: &str) -> bool {
         match result {
             Ok(()) => false,
-            Err(ref errs) => errs.contains_key(field),
+            Err(ref errs) => errs.0.get(field).map_or(false, ValidationErrorsKind::has_errors),
+        }
+    }
+
+    /// Turns the result of `validate_with_warnings` into one holding both the errors and the
+    /// warnings, to be combined with `merge` or `merge_all`
+    pub fn with_warnings(
+        result: Result<ValidationErrors, ValidationErrors>,
+    ) -> Result<(), ValidationErrors> {
+        match result {
+            Ok(warnings) if warnings.is_empty() => Ok(()),
+            Ok(warnings) => Err(warnings),
+            Err(errors) => Err(errors),
         }
     }
 
     /// Returns the combined outcome of a struct's validation result along with the nested
     /// validation result for one of its fields.
-    pub fn merge(
+    pub fn merge<F: Into<Cow<'static, str>>>(
         parent: Result<(), ValidationErrors>,
-        field: &'static str,
+        field: F,
         child: Result<(), ValidationErrors>,
     ) -> Result<(), ValidationErrors> {
         match child {
             Ok(()) => parent,
             Err(errors) => {
                 parent.and_then(|_| Err(ValidationErrors::new())).map_err(|mut parent_errors| {
-                    parent_errors.add_nested(field, ValidationErrorsKind::Struct(Box::new(errors)));
+                    parent_errors
+                        .add_nested(field.into(), ValidationErrorsKind::Struct(Box::new(errors)));
                     parent_errors
                 })
             }
@@ -78,15 +163,17 @@ impl ValidationErrors {
 
     /// Returns the combined outcome of a struct's validation result along with the nested
     /// validation result for one of its fields where that field is a vector of validating structs.
-    pub fn merge_all(
+    /// Warnings of the field itself are dropped if its items have errors or warnings.
+    pub fn merge_all<F: Into<Cow<'static, str>>>(
         parent: Result<(), ValidationErrors>,
-        field: &'static str,
+        field: F,
         children: Vec<Result<(), ValidationErrors>>,
     ) -> Result<(), ValidationErrors> {
+        let field = field.into();
         let errors = children
             .into_iter()
             .enumerate()
-            .filter_map(|(i, res)| res.err().map(|mut err| (i, err.remove(field))))
+            .filter_map(|(i, res)| res.err().map(|mut err| (i, err.remove(&field))))
             .filter_map(|(i, entry)| match entry {
                 Some(ValidationErrorsKind::Struct(errors)) => Some((i, errors)),
                 _ => None,
@@ -97,76 +184,368 @@ impl ValidationErrors {
             parent
         } else {
             parent.and_then(|_| Err(ValidationErrors::new())).map_err(|mut parent_errors| {
+                // A list can't hold errors of the field itself, the field's warnings give way to
+                // the errors of its items
+                if let Some(kind @ ValidationErrorsKind::Field(_)) = parent_errors.0.get(&field) {
+                    if !kind.has_errors() {
+                        parent_errors.remove(&field);
+                    }
+                }
                 parent_errors.add_nested(field, ValidationErrorsKind::List(errors));
                 parent_errors
             })
         }
     }
 
+    /// Returns the combined outcome of a struct's validation result along with the result of
+    /// `ValidateNested::validate_nested` for one of its fields. As with `merge_all`, the errors
+    /// of the field itself take priority over the errors of its items, its warnings don't. The
+    /// errors already reported per item, like the duplicates of `unique`, are merged index by index.
+    pub fn merge_nested<F: Into<Cow<'static, str>>>(
+        parent: Result<(), ValidationErrors>,
+        field: F,
+        child: Result<(), ValidationErrorsKind>,
+    ) -> Result<(), ValidationErrors> {
+        let kind = match child {
+            Ok(()) => return parent,
+            Err(kind) => kind,
+        };
+        let field = field.into();
+        let mut parent_errors = parent.err().unwrap_or_default();
+        if let ValidationErrorsKind::List(_) = kind {
+            match parent_errors.0.get(&field) {
+                Some(ValidationErrorsKind::List(_)) => (),
+                Some(existing) if existing.has_errors() => return Err(parent_errors),
+                Some(ValidationErrorsKind::Field(_)) => {
+                    parent_errors.remove(&field);
+                }
+                _ => (),
+            }
+        }
+        parent_errors.add_nested(field, kind);
+        Err(parent_errors)
+    }
+
     /// Returns a map of field-level validation errors found for the struct that was validated and
-    /// any of it's nested structs that are tagged for validation.
-    pub fn errors(&self) -> &HashMap<&'static str, ValidationErrorsKind> {
+    /// any of it's nested structs that are tagged for validation, in the order they were added.
+    pub fn errors(&self) -> &IndexMap<Cow<'static, str>, ValidationErrorsKind> {
         &self.0
     }
 
     /// Returns a mutable map of field-level validation errors found for the struct that was validated and
     /// any of it's nested structs that are tagged for validation.
-    pub fn errors_mut(&mut self) -> &mut HashMap<&'static str, ValidationErrorsKind> {
+    pub fn errors_mut(&mut self) -> &mut IndexMap<Cow<'static, str>, ValidationErrorsKind> {
         &mut self.0
     }
 
     /// Consume the struct, returning the validation errors found
-    pub fn into_errors(self) -> HashMap<&'static str, ValidationErrorsKind> {
+    pub fn into_errors(self) -> IndexMap<Cow<'static, str>, ValidationErrorsKind> {
         self.0
     }
 
     /// Returns a map of only field-level validation errors found for the struct that was validated.
-    pub fn field_errors(&self) -> HashMap<&'static str, &Vec<ValidationError>> {
+    pub fn field_errors(&self) -> IndexMap<Cow<'static, str>, &Vec<ValidationError>> {
         self.0
             .iter()
             .filter_map(|(k, v)| {
                 if let ValidationErrorsKind::Field(errors) = v {
-                    Some((*k, errors))
+                    Some((k.clone(), errors))
                 } else {
                     None
                 }
             })
-            .collect::<HashMap<_, _>>()
+            .collect::<IndexMap<_, _>>()
     }
 
-    pub fn add(&mut self, field: &'static str, error: ValidationError) {
-        if let ValidationErrorsKind::Field(ref mut vec) =
-            self.0.entry(field).or_insert_with(|| ValidationErrorsKind::Field(vec![]))
-        {
-            vec.push(error);
-        } else {
+    /// Adds a field-level error. If the field holds the errors of a nested struct, the error is
+    /// added to its `__all__` errors.
+    ///
+    /// # Panics
+    ///
+    /// If the field holds the errors of a list, see `add_nested_at` for a fallible version.
+    pub fn add<F: Into<Cow<'static, str>>>(&mut self, field: F, error: ValidationError) {
+        if self.add_nested_at(field, ValidationErrorsKind::Field(vec![error])).is_err() {
             panic!("Attempt to add field validation to a non-Field ValidationErrorsKind instance");
         }
     }
 
+    /// Adds the errors of `other` to these ones. Errors of the same field are merged deeply:
+    /// field errors are appended, nested structs and lists are merged and field errors added to
+    /// a nested struct go to its `__all__` errors.
+    ///
+    /// Errors of a list can only be merged with other errors of a list, nothing is changed and
+    /// the path of the first conflict is returned otherwise.
+    pub fn extend(&mut self, other: ValidationErrors) -> Result<(), MergeConflict> {
+        if let Some(path) = self.find_conflict(&other) {
+            return Err(MergeConflict { path });
+        }
+        self.extend_unchecked(other);
+        Ok(())
+    }
+
+    /// Merges `other` as the errors of the nested struct found by following the `path` of field
+    /// names, eg `&["address", "street"]`, the same way `extend` does.
+    pub fn merge_at<F: Into<Cow<'static, str>> + Clone>(
+        &mut self,
+        path: &[F],
+        other: ValidationErrors,
+    ) -> Result<(), MergeConflict> {
+        let nested = path.iter().rev().fold(other, |errors, field| errors.prefix(field.clone()));
+        self.extend(nested)
+    }
+
+    /// Adds the errors of a field, merging them with the existing ones the same way `extend` does
+    pub fn add_nested_at<F: Into<Cow<'static, str>>>(
+        &mut self,
+        field: F,
+        errors: ValidationErrorsKind,
+    ) -> Result<(), MergeConflict> {
+        let mut other = ValidationErrors::new();
+        other.0.insert(field.into(), errors);
+        self.extend(other)
+    }
+
+    /// Returns these errors as the ones of a nested struct in the given field
+    #[must_use]
+    pub fn prefix<F: Into<Cow<'static, str>>>(self, field: F) -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        if !self.is_empty() {
+            errors.0.insert(field.into(), ValidationErrorsKind::Struct(Box::new(self)));
+        }
+        errors
+    }
+
+    /// Orders the fields as they are in `fields`, typically their declaration order. The fields
+    /// that are not listed, like `__all__`, are moved last in their current order.
+    pub fn sort_fields(&mut self, fields: &[&str]) {
+        let position =
+            |field: &str| fields.iter().position(|f| *f == field).unwrap_or(fields.len());
+        self.0.sort_by(|a, _, b, _| position(a).cmp(&position(b)));
+    }
+
+    /// Only keeps the errors, at any depth, for which the predicate returns `true`. It is given
+    /// the name of the field the error is on. Fields, structs and lists left empty are removed.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str, &ValidationError) -> bool,
+    {
+        self.retain_with(&mut f);
+    }
+
+    /// Only keeps the errors, at any depth, with one of the given codes
+    pub fn filter_codes(&mut self, codes: &[&str]) {
+        self.retain(|_, error| codes.contains(&error.code.as_ref()));
+    }
+
+    /// Transforms every error, at any depth. The function is given the name of the field the
+    /// error is on.
+    #[must_use]
+    pub fn map_errors<F>(self, mut f: F) -> ValidationErrors
+    where
+        F: FnMut(&str, ValidationError) -> ValidationError,
+    {
+        self.map_with(&mut f)
+    }
+
     #[must_use]
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 
-    fn add_nested(&mut self, field: &'static str, errors: ValidationErrorsKind) {
-        if let Vacant(entry) = self.0.entry(field) {
-            entry.insert(errors);
-        } else {
-            panic!("Attempt to replace non-empty ValidationErrors entry");
-        }
+    /// Returns whether there is an error that is not a warning, at any depth
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.0.values().any(ValidationErrorsKind::has_errors)
+    }
+
+    /// Returns a copy of only the warnings, at any depth
+    #[must_use]
+    pub fn warnings(&self) -> ValidationErrors {
+        let mut warnings = self.clone();
+        warnings.retain(|_, error| error.is_warning());
+        warnings
     }
 
+    /// Splits these errors into the ones that are not warnings and the warnings
     #[must_use]
-    fn contains_key(&self, field: &'static str) -> bool {
-        self.0.contains_key(field)
+    pub fn split_warnings(self) -> (ValidationErrors, ValidationErrors) {
+        let warnings = self.warnings();
+        let mut errors = self;
+        errors.retain(|_, error| !error.is_warning());
+        (errors, warnings)
+    }
+
+    pub(crate) fn add_nested(&mut self, field: Cow<'static, str>, errors: ValidationErrorsKind) {
+        if let Err(conflict) = self.add_nested_at(field, errors) {
+            panic!("Attempt to merge ValidationErrors: {}", conflict);
+        }
+    }
+
+    /// The path of the first entry of `other` that can't be merged with these errors
+    fn find_conflict(&self, other: &ValidationErrors) -> Option<String> {
+        other.0.iter().find_map(|(field, kind)| {
+            let existing = self.0.get(field)?;
+            existing.find_conflict(kind).map(|path| format!("{}{}", field, path))
+        })
+    }
+
+    fn extend_unchecked(&mut self, other: ValidationErrors) {
+        for (field, kind) in other.0 {
+            match self.0.entry(field) {
+                Occupied(mut entry) => entry.get_mut().merge_unchecked(kind),
+                Vacant(entry) => {
+                    entry.insert(kind);
+                }
+            }
+        }
+    }
+
+    fn retain_with<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&str, &ValidationError) -> bool,
+    {
+        self.0.retain(|field, kind| match kind {
+            ValidationErrorsKind::Field(errors) => {
+                errors.retain(|error| f(field, error));
+                !errors.is_empty()
+            }
+            ValidationErrorsKind::Struct(errors) => {
+                errors.retain_with(f);
+                !errors.is_empty()
+            }
+            ValidationErrorsKind::List(list) => {
+                list.retain(|_, errors| {
+                    errors.retain_with(f);
+                    !errors.is_empty()
+                });
+                !list.is_empty()
+            }
+        });
+    }
+
+    fn map_with<F>(self, f: &mut F) -> ValidationErrors
+    where
+        F: FnMut(&str, ValidationError) -> ValidationError,
+    {
+        let errors = self
+            .0
+            .into_iter()
+            .map(|(field, kind)| {
+                let kind = match kind {
+                    ValidationErrorsKind::Field(errors) => ValidationErrorsKind::Field(
+                        errors.into_iter().map(|error| f(&field, error)).collect(),
+                    ),
+                    ValidationErrorsKind::Struct(errors) => {
+                        ValidationErrorsKind::Struct(Box::new(errors.map_with(f)))
+                    }
+                    ValidationErrorsKind::List(list) => ValidationErrorsKind::List(
+                        list.into_iter()
+                            .map(|(i, errors)| (i, Box::new(errors.map_with(f))))
+                            .collect(),
+                    ),
+                };
+                (field, kind)
+            })
+            .collect();
+        ValidationErrors(errors)
+    }
+
+    fn remove(&mut self, field: &str) -> Option<ValidationErrorsKind> {
+        self.0.shift_remove(field)
     }
+}
 
-    fn remove(&mut self, field: &'static str) -> Option<ValidationErrorsKind> {
-        self.0.remove(field)
+impl ValidationErrorsKind {
+    /// Whether there is an error that is not a warning, at any depth
+    fn has_errors(&self) -> bool {
+        match self {
+            ValidationErrorsKind::Field(errors) => errors.iter().any(|error| !error.is_warning()),
+            ValidationErrorsKind::Struct(errors) => errors.has_errors(),
+            ValidationErrorsKind::List(list) => list.values().any(|errors| errors.has_errors()),
+        }
+    }
+
+    /// The path, relative to this entry, where `other` can't be merged into it
+    fn find_conflict(&self, other: &ValidationErrorsKind) -> Option<String> {
+        let all_conflict = |errors: &ValidationErrors, field_errors: &ValidationErrorsKind| {
+            let existing = errors.0.get("__all__")?;
+            existing.find_conflict(field_errors).map(|path| format!(".__all__{}", path))
+        };
+
+        match (self, other) {
+            (ValidationErrorsKind::Field(_), ValidationErrorsKind::Field(_)) => None,
+            (ValidationErrorsKind::Struct(errors), ValidationErrorsKind::Struct(others)) => {
+                errors.find_conflict(others).map(|path| format!(".{}", path))
+            }
+            (ValidationErrorsKind::Struct(errors), field @ ValidationErrorsKind::Field(_)) => {
+                all_conflict(errors, field)
+            }
+            (field @ ValidationErrorsKind::Field(_), ValidationErrorsKind::Struct(others)) => {
+                all_conflict(others, field)
+            }
+            (ValidationErrorsKind::List(list), ValidationErrorsKind::List(others)) => {
+                others.iter().find_map(|(i, other)| {
+                    let existing = list.get(i)?;
+                    existing.find_conflict(other).map(|path| format!("[{}].{}", i, path))
+                })
+            }
+            _ => Some(String::new()),
+        }
+    }
+
+    /// Merges `other` into this entry, `find_conflict` has to be checked first
+    fn merge_unchecked(&mut self, other: ValidationErrorsKind) {
+        match (self, other) {
+            (ValidationErrorsKind::Field(errors), ValidationErrorsKind::Field(others)) => {
+                errors.extend(others)
+            }
+            (ValidationErrorsKind::Struct(errors), ValidationErrorsKind::Struct(others)) => {
+                errors.extend_unchecked(*others)
+            }
+            (ValidationErrorsKind::Struct(errors), field @ ValidationErrorsKind::Field(_)) => {
+                let mut other = ValidationErrors::new();
+                other.0.insert(Cow::from("__all__"), field);
+                errors.extend_unchecked(other);
+            }
+            (this @ ValidationErrorsKind::Field(_), ValidationErrorsKind::Struct(others)) => {
+                let mut errors = ValidationErrors::new();
+                errors.0.insert(
+                    Cow::from("__all__"),
+                    mem::replace(this, ValidationErrorsKind::Field(vec![])),
+                );
+                errors.extend_unchecked(*others);
+                *this = ValidationErrorsKind::Struct(Box::new(errors));
+            }
+            (ValidationErrorsKind::List(list), ValidationErrorsKind::List(others)) => {
+                for (i, other) in others {
+                    match list.entry(i) {
+                        BTreeEntry::Occupied(mut entry) => entry.get_mut().extend_unchecked(*other),
+                        BTreeEntry::Vacant(entry) => {
+                            entry.insert(other);
+                        }
+                    }
+                }
+            }
+            _ => unreachable!("Merging conflicting ValidationErrorsKind instances"),
+        }
     }
 }
 
+/// The errors of a list can't be merged with errors of another kind
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    /// Where the conflict is, eg `addresses[0].lines`
+    pub path: String,
+}
+
+impl fmt::Display for MergeConflict {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "cannot merge a list of errors with other errors at `{}`", self.path)
+    }
+}
+
+impl std::error::Error for MergeConflict {}
+
 impl std::error::Error for ValidationErrors {
     fn description(&self) -> &str {
         "Validation failed"
@@ -0,0 +1,124 @@
+use std::borrow::Cow;
+
+/// The chars of the string in lowercase, some chars being lowercased to several ones
+fn lowercase_chars(val: &str) -> impl DoubleEndedIterator<Item = char> + '_ {
+    val.chars().flat_map(char::to_lowercase)
+}
+
+fn has_prefix(val: &str, prefix: &str, case_insensitive: bool) -> bool {
+    if !case_insensitive {
+        return val.starts_with(prefix);
+    }
+
+    let mut chars = lowercase_chars(val);
+    lowercase_chars(prefix).all(|c| chars.next() == Some(c))
+}
+
+fn has_suffix(val: &str, suffix: &str, case_insensitive: bool) -> bool {
+    if !case_insensitive {
+        return val.ends_with(suffix);
+    }
+
+    let mut chars = lowercase_chars(val).rev();
+    lowercase_chars(suffix).rev().all(|c| chars.next() == Some(c))
+}
+
+/// Validates whether the string starts with one of the given prefixes.
+/// If `case_insensitive` is set, the comparison is done on the Unicode lowercase forms.
+#[must_use]
+pub fn validate_starts_with<'a, T>(val: T, prefixes: &[&str], case_insensitive: bool) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    prefixes.iter().any(|prefix| has_prefix(&val, prefix, case_insensitive))
+}
+
+/// Validates whether the string ends with one of the given suffixes.
+/// If `case_insensitive` is set, the comparison is done on the Unicode lowercase forms.
+#[must_use]
+pub fn validate_ends_with<'a, T>(val: T, suffixes: &[&str], case_insensitive: bool) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    suffixes.iter().any(|suffix| has_suffix(&val, suffix, case_insensitive))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{validate_ends_with, validate_starts_with};
+
+    #[test]
+    fn test_validate_starts_with() {
+        let tests = vec![
+            ("usr_123", vec!["usr_"], false, true),
+            ("org_123", vec!["usr_", "org_"], false, true),
+            ("USR_123", vec!["usr_"], false, false),
+            ("USR_123", vec!["usr_"], true, true),
+            ("ÉTÉ", vec!["été"], true, true),
+            ("us", vec!["usr_"], true, false),
+            ("anything", vec![""], false, true),
+            ("anything", vec![], false, false),
+        ];
+
+        for (input, prefixes, case_insensitive, expected) in tests {
+            assert_eq!(
+                validate_starts_with(input, &prefixes, case_insensitive),
+                expected,
+                "`{}` was not classified correctly for prefixes {:?}",
+                input,
+                prefixes
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_ends_with() {
+        let tests = vec![
+            ("photo.png", vec!["png", "jpg"], false, true),
+            ("photo.jpg", vec!["png", "jpg"], false, true),
+            ("photo.PNG", vec!["png", "jpg"], false, false),
+            ("photo.PNG", vec!["png", "jpg"], true, true),
+            ("photo.gif", vec!["png", "jpg"], true, false),
+            ("g", vec!["png"], true, false),
+            ("STRASSE", vec!["straße"], true, false),
+        ];
+
+        for (input, suffixes, case_insensitive, expected) in tests {
+            assert_eq!(
+                validate_ends_with(input, &suffixes, case_insensitive),
+                expected,
+                "`{}` was not classified correctly for suffixes {:?}",
+                input,
+                suffixes
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_starts_with_cow() {
+        let test: Cow<'static, str> = "usr_123".into();
+        assert!(validate_starts_with(test, &["usr_"], false));
+        let test: Cow<'static, str> = String::from("usr_123").into();
+        assert!(validate_starts_with(test, &["usr_"], false));
+        let test: Cow<'static, str> = "org_123".into();
+        assert!(!validate_starts_with(test, &["usr_"], false));
+        let test: Cow<'static, str> = String::from("org_123").into();
+        assert!(!validate_starts_with(test, &["usr_"], false));
+    }
+
+    #[test]
+    fn test_validate_ends_with_cow() {
+        let test: Cow<'static, str> = "a.png".into();
+        assert!(validate_ends_with(test, &["png"], false));
+        let test: Cow<'static, str> = String::from("a.png").into();
+        assert!(validate_ends_with(test, &["png"], false));
+        let test: Cow<'static, str> = "a.gif".into();
+        assert!(!validate_ends_with(test, &["png"], false));
+        let test: Cow<'static, str> = String::from("a.gif").into();
+        assert!(!validate_ends_with(test, &["png"], false));
+    }
+}
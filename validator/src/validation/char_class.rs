@@ -0,0 +1,193 @@
+use std::borrow::Cow;
+
+/// The classes of characters that can be checked by `validate_char_class`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    /// Only ASCII characters
+    Ascii,
+    /// Only letters and ASCII digits
+    Alphanumeric,
+    /// Only letters
+    Alpha,
+    /// Only ASCII digits, other numeric chars like `½` or `²` are not digits
+    Numeric,
+    /// No uppercase letters
+    Lowercase,
+    /// No lowercase letters
+    Uppercase,
+    /// No control characters nor invisible format characters, like zero-width spaces or bidi overrides
+    Printable,
+}
+
+impl CharClass {
+    /// Whether the char belongs to the class. When `ascii_only` is set, any non-ASCII char is
+    /// rejected on top of that, eg `é` is alphabetic but not ASCII alphabetic.
+    #[must_use]
+    pub fn matches(&self, c: char, ascii_only: bool) -> bool {
+        if ascii_only && !c.is_ascii() {
+            return false;
+        }
+
+        match *self {
+            CharClass::Ascii => c.is_ascii(),
+            CharClass::Alphanumeric => c.is_alphabetic() || c.is_ascii_digit(),
+            CharClass::Alpha => c.is_alphabetic(),
+            CharClass::Numeric => c.is_ascii_digit(),
+            CharClass::Lowercase => !c.is_uppercase(),
+            CharClass::Uppercase => !c.is_lowercase(),
+            CharClass::Printable => !c.is_control() && !is_format_char(c),
+        }
+    }
+}
+
+/// The ranges of format characters (general category `Cf`) as of Unicode 14
+const FORMAT_CHARS: &[(char, char)] = &[
+    ('\u{AD}', '\u{AD}'),
+    ('\u{600}', '\u{605}'),
+    ('\u{61C}', '\u{61C}'),
+    ('\u{6DD}', '\u{6DD}'),
+    ('\u{70F}', '\u{70F}'),
+    ('\u{890}', '\u{891}'),
+    ('\u{8E2}', '\u{8E2}'),
+    ('\u{180E}', '\u{180E}'),
+    ('\u{200B}', '\u{200F}'),
+    ('\u{202A}', '\u{202E}'),
+    ('\u{2060}', '\u{2064}'),
+    ('\u{2066}', '\u{206F}'),
+    ('\u{FEFF}', '\u{FEFF}'),
+    ('\u{FFF9}', '\u{FFFB}'),
+    ('\u{110BD}', '\u{110BD}'),
+    ('\u{110CD}', '\u{110CD}'),
+    ('\u{13430}', '\u{13438}'),
+    ('\u{1BCA0}', '\u{1BCA3}'),
+    ('\u{1D173}', '\u{1D17A}'),
+    ('\u{E0001}', '\u{E0001}'),
+    ('\u{E0020}', '\u{E007F}'),
+];
+
+fn is_format_char(c: char) -> bool {
+    FORMAT_CHARS.iter().any(|&(start, end)| (start..=end).contains(&c))
+}
+
+/// Returns the index (in chars, not bytes) and the value of the first char of the string
+/// that doesn't belong to the given class, if any
+#[must_use]
+pub fn first_invalid_char<'a, T>(
+    val: T,
+    class: CharClass,
+    ascii_only: bool,
+) -> Option<(usize, char)>
+where
+    T: Into<Cow<'a, str>>,
+{
+    val.into().chars().enumerate().find(|(_, c)| !class.matches(*c, ascii_only))
+}
+
+/// Validates whether all the chars of the string belong to the given class.
+/// When `ascii_only` is set, the string also has to be ASCII.
+/// An empty string is valid.
+#[must_use]
+pub fn validate_char_class<'a, T>(val: T, class: CharClass, ascii_only: bool) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    first_invalid_char(val, class, ascii_only).is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{first_invalid_char, validate_char_class, CharClass};
+
+    #[test]
+    fn test_validate_char_class() {
+        let tests = vec![
+            ("abc-123", CharClass::Ascii, true),
+            ("abcé", CharClass::Ascii, false),
+            ("abc123", CharClass::Alphanumeric, true),
+            ("日本123", CharClass::Alphanumeric, true),
+            ("abc½", CharClass::Alphanumeric, false),
+            ("abc 123", CharClass::Alphanumeric, false),
+            ("abc", CharClass::Alpha, true),
+            ("héllo", CharClass::Alpha, true),
+            ("abc1", CharClass::Alpha, false),
+            ("0123", CharClass::Numeric, true),
+            ("٣4", CharClass::Numeric, false),
+            ("12.3", CharClass::Numeric, false),
+            ("1½", CharClass::Numeric, false),
+            ("x²", CharClass::Numeric, false),
+            ("Ⅻ", CharClass::Numeric, false),
+            ("abc-123_é", CharClass::Lowercase, true),
+            ("abC", CharClass::Lowercase, false),
+            ("ABC-123_É", CharClass::Uppercase, true),
+            ("ABc", CharClass::Uppercase, false),
+            ("Hello, world! 日本", CharClass::Printable, true),
+            ("Hello\nworld", CharClass::Printable, false),
+            ("\u{009F}", CharClass::Printable, false),
+            ("abc\u{202E}def", CharClass::Printable, false),
+            ("a\u{200B}b", CharClass::Printable, false),
+            ("\u{FEFF}abc", CharClass::Printable, false),
+            ("", CharClass::Alpha, true),
+        ];
+
+        for (input, class, expected) in tests {
+            assert_eq!(
+                validate_char_class(input, class, false),
+                expected,
+                "`{}` was not classified correctly for {:?}",
+                input,
+                class
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_char_class_ascii_only() {
+        let tests = vec![
+            ("abc123", CharClass::Alphanumeric, true),
+            ("日本123", CharClass::Alphanumeric, false),
+            ("hello", CharClass::Alpha, true),
+            ("héllo", CharClass::Alpha, false),
+            ("0123", CharClass::Numeric, true),
+            ("٣4", CharClass::Numeric, false),
+            ("abc-123", CharClass::Lowercase, true),
+            ("abc-é", CharClass::Lowercase, false),
+            ("ABC-123", CharClass::Uppercase, true),
+            ("ABC-É", CharClass::Uppercase, false),
+            ("Hello, world!", CharClass::Printable, true),
+            ("Hello 日本", CharClass::Printable, false),
+        ];
+
+        for (input, class, expected) in tests {
+            assert_eq!(
+                validate_char_class(input, class, true),
+                expected,
+                "`{}` was not classified correctly for ASCII {:?}",
+                input,
+                class
+            );
+        }
+    }
+
+    #[test]
+    fn test_first_invalid_char() {
+        assert_eq!(first_invalid_char("abc", CharClass::Alpha, false), None);
+        assert_eq!(first_invalid_char("ab1c2", CharClass::Alpha, false), Some((2, '1')));
+        // the index is in chars, not in bytes
+        assert_eq!(first_invalid_char("日本-", CharClass::Alpha, false), Some((2, '-')));
+        assert_eq!(first_invalid_char("aé", CharClass::Alpha, true), Some((1, 'é')));
+    }
+
+    #[test]
+    fn test_validate_char_class_cow() {
+        let test: Cow<'static, str> = "abc".into();
+        assert!(validate_char_class(test, CharClass::Alpha, true));
+        let test: Cow<'static, str> = String::from("abc").into();
+        assert!(validate_char_class(test, CharClass::Alpha, true));
+        let test: Cow<'static, str> = "abc1".into();
+        assert!(!validate_char_class(test, CharClass::Alpha, true));
+        let test: Cow<'static, str> = String::from("abc1").into();
+        assert!(!validate_char_class(test, CharClass::Alpha, true));
+    }
+}
@@ -1,17 +1,21 @@
 use crate::traits::Contains;
 
 /// Validates whether the value contains the needle
-/// The value needs to implement the Contains trait, which is implement on String, str and Hashmap<String>
-/// by default.
+/// The value needs to implement the Contains trait, which is implemented on strings (substring),
+/// lists (element) and sets and maps (key) by default.
 #[must_use]
-pub fn validate_contains<T: Contains>(val: T, needle: &str) -> bool {
+pub fn validate_contains<T, N>(val: T, needle: &N) -> bool
+where
+    T: Contains<N>,
+    N: ?Sized,
+{
     val.has_element(needle)
 }
 
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
     use super::*;
 
@@ -54,4 +58,45 @@ mod tests {
         let test: Cow<'static, str> = String::from("hey").into();
         assert!(!validate_contains(test, "o"));
     }
+
+    #[test]
+    fn test_validate_contains_list_element() {
+        let tags = vec!["primary".to_string(), "urgent".to_string()];
+        assert!(validate_contains(&tags, "primary"));
+        assert!(!validate_contains(&tags, "prim"));
+        assert!(validate_contains(&tags[..], &"urgent"));
+        assert!(validate_contains([1u32, 2, 3], &2));
+        assert!(!validate_contains(vec![1u32, 2, 3], &0));
+    }
+
+    #[test]
+    fn test_validate_contains_set_element() {
+        let set: HashSet<String> = vec!["primary".to_string()].into_iter().collect();
+        assert!(validate_contains(&set, "primary"));
+        assert!(!validate_contains(&set, "urgent"));
+        let set: BTreeSet<u32> = vec![0, 1].into_iter().collect();
+        assert!(validate_contains(&set, &0));
+        assert!(!validate_contains(&set, &2));
+    }
+
+    #[test]
+    fn test_validate_contains_btreemap_key() {
+        let mut map = BTreeMap::new();
+        map.insert(0u32, "zero");
+        assert!(validate_contains(&map, &0));
+        assert!(!validate_contains(&map, &1));
+    }
+
+    #[test]
+    fn test_validate_contains_indexmap_key() {
+        use indexmap::{IndexMap, IndexSet};
+
+        let mut map = IndexMap::new();
+        map.insert("hey".to_string(), 1);
+        assert!(validate_contains(&map, "hey"));
+        assert!(!validate_contains(&map, "bob"));
+        let set: IndexSet<u8> = vec![1, 2].into_iter().collect();
+        assert!(validate_contains(&set, &1));
+        assert!(!validate_contains(&set, &3));
+    }
 }
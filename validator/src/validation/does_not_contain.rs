@@ -1,23 +1,27 @@
 use crate::traits::Contains;
 
 /// Validates whether the value does not contain the needle
-/// The value needs to implement the Contains trait, which is implement on String, str and Hashmap<String>
-/// by default.
+/// The value needs to implement the Contains trait, which is implemented on strings (substring),
+/// lists (element) and sets and maps (key) by default.
 #[must_use]
-pub fn validate_does_not_contain<T: Contains>(val: T, needle: &str) -> bool {
+pub fn validate_does_not_contain<T, N>(val: T, needle: &N) -> bool
+where
+    T: Contains<N>,
+    N: ?Sized,
+{
     !val.has_element(needle)
 }
 
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
     use super::*;
 
     #[test]
     fn test_validate_does_not_contain_string() {
-        assert_eq!(validate_does_not_contain("hey", "e"), false);
+        assert!(!validate_does_not_contain("hey", "e"));
     }
 
     #[test]
@@ -29,7 +33,7 @@ mod tests {
     fn test_validate_does_not_contain_hashmap_key() {
         let mut map = HashMap::new();
         map.insert("hey".to_string(), 1);
-        assert_eq!(validate_does_not_contain(map, "hey"), false);
+        assert!(!validate_does_not_contain(map, "hey"));
     }
 
     #[test]
@@ -42,9 +46,9 @@ mod tests {
     #[test]
     fn test_validate_does_not_contain_cow() {
         let test: Cow<'static, str> = "hey".into();
-        assert_eq!(validate_does_not_contain(test, "e"), false);
+        assert!(!validate_does_not_contain(test, "e"));
         let test: Cow<'static, str> = String::from("hey").into();
-        assert_eq!(validate_does_not_contain(test, "e"), false);
+        assert!(!validate_does_not_contain(test, "e"));
     }
 
     #[test]
@@ -54,4 +58,19 @@ mod tests {
         let test: Cow<'static, str> = String::from("hey").into();
         assert!(validate_does_not_contain(test, "o"));
     }
+
+    #[test]
+    fn test_validate_does_not_contain_collection_element() {
+        let tags = vec!["primary".to_string()];
+        assert!(!validate_does_not_contain(&tags, "primary"));
+        assert!(validate_does_not_contain(&tags, "urgent"));
+        let set: HashSet<u32> = vec![0, 1].into_iter().collect();
+        assert!(!validate_does_not_contain(&set, &0));
+        assert!(validate_does_not_contain(&set, &2));
+        let mut map = BTreeMap::new();
+        map.insert(1i64, ());
+        assert!(validate_does_not_contain(map, &0));
+        let set: BTreeSet<char> = "abc".chars().collect();
+        assert!(!validate_does_not_contain(set, &'a'));
+    }
 }
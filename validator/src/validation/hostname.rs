@@ -0,0 +1,263 @@
+use idna::domain_to_ascii;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::borrow::Cow;
+
+#[cfg(feature = "public_suffix")]
+use publicsuffix::{List, Psl};
+
+lazy_static! {
+    // RFC 1123: labels of 1 to 63 letters, digits or hyphens separated by dots,
+    // a label can't start or end with a hyphen
+    static ref HOSTNAME_RE: Regex = Regex::new(
+        r"(?i)^[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?(?:\.[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?)*$"
+    ).unwrap();
+}
+
+#[cfg(feature = "public_suffix")]
+lazy_static! {
+    // Snapshot of https://publicsuffix.org/list/public_suffix_list.dat
+    static ref PUBLIC_SUFFIX_LIST: List = include_str!("public_suffix_list.dat").parse().unwrap();
+}
+
+/// The maximum length of a hostname in its textual form (RFC 1035 section 2.3.4 minus the
+/// length octets)
+const MAX_HOSTNAME_LENGTH: usize = 253;
+
+/// Checks whether the given ASCII string is made of valid RFC 1123 labels, without any length check
+/// on the whole name
+#[must_use]
+pub(crate) fn is_valid_labels(val: &str) -> bool {
+    HOSTNAME_RE.is_match(val)
+}
+
+/// Returns the ASCII form of the hostname if it is valid, converting an
+/// [IDN](https://en.wikipedia.org/wiki/Internationalized_domain_name) to punycode if needed
+fn to_ascii_hostname(val: &str) -> Option<Cow<'_, str>> {
+    let ascii = if is_valid_labels(val) {
+        Cow::Borrowed(val)
+    } else {
+        match domain_to_ascii(val) {
+            Ok(d) if is_valid_labels(&d) => Cow::Owned(d),
+            _ => return None,
+        }
+    };
+
+    if ascii.len() > MAX_HOSTNAME_LENGTH {
+        return None;
+    }
+
+    Some(ascii)
+}
+
+/// Returns the ASCII form of the domain if it is valid: a hostname made of at least 2 labels
+/// where the top-level one is not all-numeric, optionally starting with a `*.` wildcard.
+/// The wildcard is not part of the returned value.
+fn to_ascii_domain(val: &str, allow_wildcard: bool) -> Option<Cow<'_, str>> {
+    let name = match val.strip_prefix("*.") {
+        Some(rest) if allow_wildcard => rest,
+        _ => val,
+    };
+    let wildcard_len = val.len() - name.len();
+
+    let ascii = to_ascii_hostname(name)?;
+    if ascii.len() + wildcard_len > MAX_HOSTNAME_LENGTH {
+        return None;
+    }
+
+    // RFC 3696 section 2: a top-level domain can't be all-numeric, which also rules out IPs
+    match ascii.rsplit_once('.') {
+        Some((_, tld)) if !tld.bytes().all(|b| b.is_ascii_digit()) => Some(ascii),
+        _ => None,
+    }
+}
+
+/// Validates whether the given string is a hostname as defined in [RFC 1123](https://tools.ietf.org/html/rfc1123#page-13):
+/// dot-separated labels of at most 63 characters for a total of at most 253 characters.
+/// Internationalized domain names are validated on their punycode form.
+#[must_use]
+pub fn validate_hostname<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    to_ascii_hostname(&val.into()).is_some()
+}
+
+/// Validates whether the given string is a domain name: a valid hostname with at least 2 labels
+/// and a top-level domain that is not all-numeric.
+/// If `allow_wildcard` is set, the name can start with a `*.` wildcard label, eg `*.example.com`.
+#[must_use]
+pub fn validate_domain<'a, T>(val: T, allow_wildcard: bool) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    to_ascii_domain(&val.into(), allow_wildcard).is_some()
+}
+
+/// Validates whether the given string is a domain name (see `validate_domain`) ending with a
+/// suffix of the [public suffix list](https://publicsuffix.org) and with at least one label
+/// before that suffix, ie it can be registered. `co.uk` and `example.invalid` are rejected
+/// while `example.co.uk` is accepted.
+#[cfg(feature = "public_suffix")]
+#[must_use]
+pub fn validate_public_domain<'a, T>(val: T, allow_wildcard: bool) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    match to_ascii_domain(&val.into(), allow_wildcard) {
+        Some(ascii) => {
+            let ascii = ascii.to_ascii_lowercase();
+            match PUBLIC_SUFFIX_LIST.domain(ascii.as_bytes()) {
+                Some(domain) => domain.suffix().is_known(),
+                None => false,
+            }
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{validate_domain, validate_hostname};
+
+    #[test]
+    fn test_validate_hostname() {
+        let tests = vec![
+            ("localhost", true),
+            ("example.com", true),
+            ("sub.example.com", true),
+            ("EXAMPLE.COM", true),
+            ("valid-----hyphens.com", true),
+            ("123.example.com", true),
+            ("127.0.0.1", true),
+            ("xn--d1acufc.xn--p1ai", true),
+            ("домен.рф", true),
+            ("उदाहरण.परीक्षा", true),
+            ("a.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", true),
+            // 64 * a
+            ("a.aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", false),
+            ("", false),
+            (".", false),
+            ("example.com.", false),
+            (".example.com", false),
+            ("example..com", false),
+            ("-example.com", false),
+            ("example-.com", false),
+            ("exam_ple.com", false),
+            ("exa mple.com", false),
+            ("example.com\n", false),
+            ("*.example.com", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                validate_hostname(input),
+                expected,
+                "Hostname `{}` was not classified correctly",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_hostname_max_length() {
+        // 4 labels of 63 characters separated by dots: 255 characters
+        let label = "a".repeat(63);
+        let test = [label.as_str(); 4].join(".");
+        assert!(!validate_hostname(test.as_str()));
+        // 253 characters
+        assert!(validate_hostname(&test[2..]));
+    }
+
+    #[test]
+    fn test_validate_hostname_cow() {
+        let test: Cow<'static, str> = "example.com".into();
+        assert!(validate_hostname(test));
+        let test: Cow<'static, str> = String::from("example.com").into();
+        assert!(validate_hostname(test));
+        let test: Cow<'static, str> = "-example.com".into();
+        assert!(!validate_hostname(test));
+        let test: Cow<'static, str> = String::from("-example.com").into();
+        assert!(!validate_hostname(test));
+    }
+
+    #[test]
+    fn test_validate_domain() {
+        let tests = vec![
+            ("example.com", true),
+            ("sub.example.co.uk", true),
+            ("домен.рф", true),
+            ("localhost", false),
+            ("127.0.0.1", false),
+            ("example.123", false),
+            ("*.example.com", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                validate_domain(input, false),
+                expected,
+                "Domain `{}` was not classified correctly",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_domain_wildcard() {
+        let tests = vec![
+            ("*.example.com", true),
+            ("example.com", true),
+            ("*.com", false),
+            ("*", false),
+            ("*.", false),
+            ("**.example.com", false),
+            ("*.*.example.com", false),
+            ("sub.*.example.com", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                validate_domain(input, true),
+                expected,
+                "Wildcard domain `{}` was not classified correctly",
+                input
+            );
+        }
+    }
+
+    #[cfg(feature = "public_suffix")]
+    #[test]
+    fn test_validate_public_domain() {
+        use super::validate_public_domain;
+
+        let tests = vec![
+            ("example.com", true),
+            ("EXAMPLE.COM", true),
+            ("www.example.co.uk", true),
+            ("example.github.io", true),
+            ("домен.рф", true),
+            ("com", false),
+            ("co.uk", false),
+            ("github.io", false),
+            ("example.invalid", false),
+            ("example.notarealtld", false),
+            ("localhost", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                validate_public_domain(input, false),
+                expected,
+                "Public domain `{}` was not classified correctly",
+                input
+            );
+        }
+
+        assert!(validate_public_domain("*.example.com", true));
+        assert!(!validate_public_domain("*.co.uk", true));
+    }
+}
@@ -1,10 +1,25 @@
 use crate::traits::HasLen;
 
+/// How the length of a string is measured by the `length` validator.
+/// It has no effect on collections, which are always measured by their number of elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthMode {
+    /// Number of bytes of the UTF-8 encoding, what a database column usually limits
+    Bytes,
+    /// Number of Unicode scalar values, the default
+    Chars,
+    /// Number of extended grapheme clusters, what a user perceives as characters
+    Graphemes,
+    /// Number of UTF-16 code units, what JavaScript's `String.length` returns
+    Utf16,
+}
+
 /// Validates the length of the value given.
 /// If the validator has `equal` set, it will ignore any `min` and `max` value.
 ///
 /// If you apply it on String, don't forget that the length can be different
-/// from the number of visual characters for Unicode
+/// from the number of visual characters for Unicode: it counts chars, see
+/// `validate_length_with_mode` to count bytes, graphemes or UTF-16 code units instead.
 #[must_use]
 pub fn validate_length<T: HasLen>(
     value: T,
@@ -12,7 +27,20 @@ pub fn validate_length<T: HasLen>(
     max: Option<u64>,
     equal: Option<u64>,
 ) -> bool {
-    let val_length = value.length();
+    validate_length_with_mode(value, min, max, equal, LengthMode::Chars)
+}
+
+/// Validates the length of the value given, measured according to `mode` if the value is a string.
+/// If the validator has `equal` set, it will ignore any `min` and `max` value.
+#[must_use]
+pub fn validate_length_with_mode<T: HasLen>(
+    value: T,
+    min: Option<u64>,
+    max: Option<u64>,
+    equal: Option<u64>,
+    mode: LengthMode,
+) -> bool {
+    let val_length = value.length_with_mode(mode);
 
     if let Some(eq) = equal {
         return val_length == eq;
@@ -36,7 +64,7 @@ pub fn validate_length<T: HasLen>(
 mod tests {
     use std::borrow::Cow;
 
-    use super::validate_length;
+    use super::{validate_length, validate_length_with_mode, LengthMode};
 
     #[test]
     fn test_validate_length_equal_overrides_min_max() {
@@ -76,4 +104,32 @@ mod tests {
     fn test_validate_length_unicode_chars() {
         assert!(validate_length("日本", None, None, Some(2)));
     }
+
+    #[test]
+    fn test_validate_length_cow_counts_chars() {
+        let test: Cow<'static, str> = "日本".into();
+        assert!(validate_length(test, None, None, Some(2)));
+    }
+
+    #[test]
+    fn test_validate_length_modes() {
+        // "e" followed by a combining acute accent, then a family emoji made of 3 chars joined
+        // by 2 zero width joiners
+        let test = "e\u{301}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert!(validate_length_with_mode(test, None, None, Some(21), LengthMode::Bytes));
+        assert!(validate_length_with_mode(test, None, None, Some(7), LengthMode::Chars));
+        assert!(validate_length_with_mode(test, None, None, Some(2), LengthMode::Graphemes));
+        assert!(validate_length_with_mode(test, None, None, Some(10), LengthMode::Utf16));
+    }
+
+    #[test]
+    fn test_validate_length_mode_max() {
+        assert!(validate_length_with_mode("日本", None, Some(2), None, LengthMode::Chars));
+        assert!(!validate_length_with_mode("日本", None, Some(2), None, LengthMode::Bytes));
+    }
+
+    #[test]
+    fn test_validate_length_mode_ignored_for_collections() {
+        assert!(validate_length_with_mode(vec!["日本"], None, None, Some(1), LengthMode::Bytes));
+    }
 }
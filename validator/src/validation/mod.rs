@@ -1,15 +1,21 @@
+pub mod affix;
 #[cfg(feature = "card")]
 pub mod cards;
+pub mod char_class;
 pub mod contains;
 pub mod does_not_contain;
 pub mod email;
+pub mod hostname;
 pub mod ip;
 pub mod length;
 pub mod must_match;
 #[cfg(feature = "unic")]
 pub mod non_control_character;
+pub mod non_empty;
+pub mod one_of;
 #[cfg(feature = "phone")]
 pub mod phone;
 pub mod range;
 pub mod required;
+pub mod unique;
 pub mod urls;
@@ -0,0 +1,75 @@
+use std::borrow::Cow;
+
+use crate::traits::HasLen;
+
+/// Validates whether the value has a length greater than 0: a string with at least one char
+/// or a collection with at least one element
+#[must_use]
+pub fn validate_non_empty<T: HasLen>(val: T) -> bool {
+    val.length() > 0
+}
+
+/// Validates whether the string has at least one char that isn't whitespace, as defined by the
+/// Unicode `White_Space` property
+#[must_use]
+pub fn validate_not_blank<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    !val.into().trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    use super::{validate_non_empty, validate_not_blank};
+
+    #[test]
+    fn test_validate_non_empty() {
+        assert!(validate_non_empty("a"));
+        assert!(validate_non_empty(" "));
+        assert!(!validate_non_empty(""));
+        assert!(validate_non_empty(String::from("a")));
+        assert!(!validate_non_empty(String::new()));
+        assert!(validate_non_empty(vec![1]));
+        assert!(!validate_non_empty(Vec::<u8>::new()));
+        assert!(!validate_non_empty(HashMap::<String, u8>::new()));
+    }
+
+    #[test]
+    fn test_validate_not_blank() {
+        let tests = vec![
+            ("a", true),
+            ("  a  ", true),
+            ("", false),
+            ("   ", false),
+            ("\t\r\n", false),
+            // no-break space and ideographic space
+            ("\u{a0}\u{3000}", false),
+            ("\u{3000}日本\u{3000}", true),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                validate_not_blank(input),
+                expected,
+                "`{:?}` was not classified correctly",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_not_blank_cow() {
+        let test: Cow<'static, str> = "a".into();
+        assert!(validate_not_blank(test));
+        let test: Cow<'static, str> = String::from("a").into();
+        assert!(validate_not_blank(test));
+        let test: Cow<'static, str> = " ".into();
+        assert!(!validate_not_blank(test));
+        let test: Cow<'static, str> = String::from(" ").into();
+        assert!(!validate_not_blank(test));
+    }
+}
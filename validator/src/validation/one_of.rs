@@ -0,0 +1,86 @@
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+use crate::ValidationError;
+
+/// Validates whether the value is equal to one of the allowed values.
+/// The value can be compared to values of another type, eg a `String` to `&str`.
+#[must_use]
+pub fn validate_one_of<T, U>(val: &T, allowed: &[U]) -> bool
+where
+    T: ?Sized + PartialEq<U>,
+{
+    allowed.iter().any(|a| val == a)
+}
+
+/// Validates whether the value is different from all the disallowed values
+#[must_use]
+pub fn validate_none_of<T, U>(val: &T, disallowed: &[U]) -> bool
+where
+    T: ?Sized + PartialEq<U>,
+{
+    !validate_one_of(val, disallowed)
+}
+
+/// Adds the values of a `one_of`/`none_of` path to the params of its error. Used by the derive so
+/// values that don't implement `Serialize` fail to compile with an error naming this function.
+#[doc(hidden)]
+pub fn one_of_path_values_must_implement_serialize<T>(err: &mut ValidationError, values: &T)
+where
+    T: ?Sized + Serialize,
+{
+    err.add_param(Cow::from("values"), values);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{validate_none_of, validate_one_of};
+
+    #[test]
+    fn test_validate_one_of() {
+        assert!(validate_one_of(&String::from("draft"), &["draft", "published"]));
+        assert!(!validate_one_of(&String::from("deleted"), &["draft", "published"]));
+        assert!(validate_one_of(&"draft", &["draft"]));
+        assert!(validate_one_of(&2, &[1, 2, 3]));
+        assert!(!validate_one_of(&4u8, &[1, 2, 3]));
+        assert!(validate_one_of(&0.5, &[0.5, 1.0]));
+        assert!(!validate_one_of::<char, char>(&'a', &[]));
+    }
+
+    #[test]
+    fn test_validate_none_of() {
+        assert!(validate_none_of(&String::from("bob"), &["admin", "root"]));
+        assert!(!validate_none_of(&String::from("root"), &["admin", "root"]));
+        assert!(validate_none_of::<i32, i32>(&0, &[]));
+        assert!(!validate_none_of(&-1i64, &[-1, 0]));
+    }
+
+    #[test]
+    fn test_validate_one_of_custom_type() {
+        #[derive(PartialEq)]
+        enum Status {
+            Draft,
+            Published,
+            Archived,
+        }
+
+        const VISIBLE: &[Status] = &[Status::Draft, Status::Published];
+        assert!(validate_one_of(&Status::Draft, VISIBLE));
+        assert!(!validate_one_of(&Status::Archived, VISIBLE));
+    }
+
+    #[test]
+    fn test_validate_one_of_cow() {
+        let test: Cow<'static, str> = "draft".into();
+        assert!(validate_one_of(&test, &["draft", "published"]));
+        let test: Cow<'static, str> = String::from("draft").into();
+        assert!(validate_one_of(&test, &["draft", "published"]));
+        let test: Cow<'static, str> = "deleted".into();
+        assert!(!validate_one_of(&test, &["draft", "published"]));
+        let test: Cow<'static, str> = String::from("deleted").into();
+        assert!(!validate_one_of(&test, &["draft", "published"]));
+    }
+}
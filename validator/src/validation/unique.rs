@@ -0,0 +1,61 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Returns the index of every item equal to a previous one along with the index of the first
+/// occurrence of that item, in order. `[a, b, a, a]` gives `[(2, 0), (3, 0)]`.
+#[must_use]
+pub fn duplicate_indices<I>(items: I) -> Vec<(usize, usize)>
+where
+    I: IntoIterator,
+    I::Item: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut duplicates = vec![];
+
+    for (index, item) in items.into_iter().enumerate() {
+        match seen.entry(item) {
+            Entry::Occupied(first) => duplicates.push((index, *first.get())),
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+            }
+        }
+    }
+
+    duplicates
+}
+
+/// Validates whether all the items are different from each other
+#[must_use]
+pub fn validate_unique<I>(items: I) -> bool
+where
+    I: IntoIterator,
+    I::Item: Eq + Hash,
+{
+    duplicate_indices(items).is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{duplicate_indices, validate_unique};
+
+    #[test]
+    fn test_validate_unique() {
+        assert!(validate_unique(vec!["a", "b", "c"]));
+        assert!(validate_unique(Vec::<u32>::new()));
+        assert!(!validate_unique([1, 2, 1]));
+        assert!(!validate_unique(vec!["a".to_string(), "a".to_string()]));
+    }
+
+    #[test]
+    fn test_duplicate_indices() {
+        assert_eq!(duplicate_indices(vec!["a", "b", "a", "a", "b"]), vec![(2, 0), (3, 0), (4, 1)]);
+        assert_eq!(duplicate_indices([1, 2, 3]), vec![]);
+    }
+
+    #[test]
+    fn test_duplicate_indices_by_key() {
+        let emails = ["Bob@example.com", "alice@example.com", "bob@example.com"];
+        assert_eq!(duplicate_indices(emails.iter().map(|e| e.to_lowercase())), vec![(2, 0)]);
+    }
+}
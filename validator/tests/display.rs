@@ -1,4 +1,4 @@
-#[cfg(derive)]
+#[cfg(feature = "derive")]
 mod tests {
     use validator::Validate;
 
@@ -52,4 +52,21 @@ mod tests {
         let err = format!("{}", bad_baz.validate().unwrap_err());
         assert_eq!(err, "baz[0].foo: Please provide a valid foo!");
     }
+
+    #[derive(Validate)]
+    struct Qux {
+        #[validate(length(min = 8, severity = "warning", message = "consider a longer password"))]
+        password: String,
+        #[validate(length(min = 2, severity = "warning"))]
+        name: String,
+    }
+
+    #[test]
+    fn test_warnings() {
+        let qux = Qux { password: "hi".into(), name: "a".into() };
+        let err = format!("{}", qux.validate_with_warnings().unwrap());
+        let lines = err.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "password: consider a longer password (warning)");
+        assert!(lines[1].starts_with("name: Validation warning: length"));
+    }
 }
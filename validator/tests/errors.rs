@@ -0,0 +1,311 @@
+use std::borrow::Cow;
+
+use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
+
+fn field_errors(fields: &[(&'static str, &'static str)]) -> ValidationErrors {
+    let mut errors = ValidationErrors::new();
+    for (field, code) in fields {
+        errors.add(*field, ValidationError::new(code));
+    }
+    errors
+}
+
+fn list(items: Vec<(usize, ValidationErrors)>) -> ValidationErrorsKind {
+    ValidationErrorsKind::List(items.into_iter().map(|(i, e)| (i, Box::new(e))).collect())
+}
+
+fn nested<'a>(errors: &'a ValidationErrors, field: &str) -> &'a ValidationErrors {
+    match errors.errors()[field] {
+        ValidationErrorsKind::Struct(ref errors) => errors,
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[test]
+fn test_extend_appends_field_errors() {
+    let mut errors = field_errors(&[("name", "length")]);
+    errors.extend(field_errors(&[("name", "regex"), ("mail", "email")])).unwrap();
+
+    let errs = errors.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs["name"].len(), 2);
+    assert_eq!(errs["name"][0].code, "length");
+    assert_eq!(errs["name"][1].code, "regex");
+    assert_eq!(errs["mail"][0].code, "email");
+}
+
+#[test]
+fn test_extend_merges_nested_structs_deeply() {
+    let mut errors = field_errors(&[("street", "length")]).prefix("address");
+    errors.extend(field_errors(&[("zip", "regex")]).prefix("address")).unwrap();
+
+    let address = nested(&errors, "address").field_errors();
+    assert_eq!(address.len(), 2);
+    assert_eq!(address["street"][0].code, "length");
+    assert_eq!(address["zip"][0].code, "regex");
+}
+
+#[test]
+fn test_field_errors_go_to_all_of_nested_struct() {
+    let mut errors = field_errors(&[("zip", "regex")]).prefix("address");
+    errors.add("address", ValidationError::new("custom"));
+    assert_eq!(nested(&errors, "address").field_errors()["__all__"][0].code, "custom");
+
+    // and the other way around
+    let mut errors = field_errors(&[("address", "custom")]);
+    errors.merge_at(&["address"], field_errors(&[("zip", "regex")])).unwrap();
+    let address = nested(&errors, "address").field_errors();
+    assert_eq!(address["__all__"][0].code, "custom");
+    assert_eq!(address["zip"][0].code, "regex");
+}
+
+#[test]
+fn test_extend_merges_lists_by_index() {
+    let mut errors = ValidationErrors::new();
+    errors.add_nested_at("items", list(vec![(0, field_errors(&[("name", "length")]))])).unwrap();
+    errors
+        .add_nested_at(
+            "items",
+            list(vec![
+                (0, field_errors(&[("name", "regex")])),
+                (2, field_errors(&[("id", "range")])),
+            ]),
+        )
+        .unwrap();
+
+    match errors.errors()["items"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list.len(), 2);
+            assert_eq!(list[&0].field_errors()["name"].len(), 2);
+            assert_eq!(list[&2].field_errors()["id"][0].code, "range");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[test]
+fn test_extend_reports_conflicts_without_changing_anything() {
+    let mut errors = field_errors(&[("items", "length"), ("name", "length")]);
+    let mut other = field_errors(&[("name", "regex")]);
+    other.add_nested_at("items", list(vec![(0, field_errors(&[("id", "range")]))])).unwrap();
+
+    let conflict = errors.extend(other).unwrap_err();
+    assert_eq!(conflict.path, "items");
+    assert_eq!(conflict.to_string(), "cannot merge a list of errors with other errors at `items`");
+    assert_eq!(errors, field_errors(&[("items", "length"), ("name", "length")]));
+
+    // lists can't be merged into nested structs either
+    let mut errors = field_errors(&[("id", "range")]).prefix("items");
+    let mut tags = ValidationErrors::new();
+    tags.add_nested_at("tags", list(vec![(0, field_errors(&[("id", "range")]))])).unwrap();
+    assert!(errors.merge_at(&["items"], tags).is_ok());
+    let conflict = errors.merge_at(&["items"], field_errors(&[("tags", "length")])).unwrap_err();
+    assert_eq!(conflict.path, "items.tags");
+}
+
+#[test]
+fn test_conflict_path_in_nested_lists() {
+    let mut tags = ValidationErrors::new();
+    tags.add_nested_at("tags", list(vec![(0, field_errors(&[("id", "range")]))])).unwrap();
+    let mut errors = ValidationErrors::new();
+    errors.add_nested_at("items", list(vec![(1, tags)])).unwrap();
+
+    let other = list(vec![(1, field_errors(&[("tags", "length")]))]);
+    let conflict = errors.add_nested_at("items", other).unwrap_err();
+    assert_eq!(conflict.path, "items[1].tags");
+}
+
+#[test]
+#[should_panic(
+    expected = "Attempt to add field validation to a non-Field ValidationErrorsKind instance"
+)]
+fn test_add_to_list_panics() {
+    let mut errors = ValidationErrors::new();
+    errors.add_nested_at("items", list(vec![(0, field_errors(&[("id", "range")]))])).unwrap();
+    errors.add("items", ValidationError::new("length"));
+}
+
+#[test]
+fn test_prefix() {
+    let errors = field_errors(&[("zip", "regex")]).prefix("address").prefix("user");
+    let address = nested(nested(&errors, "user"), "address");
+    assert_eq!(address.field_errors()["zip"][0].code, "regex");
+
+    assert!(ValidationErrors::new().prefix("user").is_empty());
+}
+
+#[test]
+fn test_retain_and_filter_codes() {
+    let mut errors = field_errors(&[("name", "length"), ("name", "regex"), ("mail", "email")]);
+    errors.merge_at(&["address"], field_errors(&[("zip", "regex")])).unwrap();
+    errors.add_nested_at("items", list(vec![(0, field_errors(&[("id", "range")]))])).unwrap();
+
+    let mut filtered = errors.clone();
+    filtered.filter_codes(&["regex"]);
+    assert_eq!(filtered.errors().len(), 2);
+    assert_eq!(filtered.field_errors()["name"].len(), 1);
+    assert_eq!(nested(&filtered, "address").field_errors()["zip"][0].code, "regex");
+
+    // the predicate is given the field the error is on, empty entries are removed
+    errors.retain(|field, _| field != "zip" && field != "id");
+    assert_eq!(errors.errors().len(), 2);
+    assert!(!errors.errors().contains_key("address"));
+    assert!(!errors.errors().contains_key("items"));
+
+    errors.retain(|_, _| false);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_map_errors() {
+    let mut errors = field_errors(&[("name", "length")]);
+    errors.merge_at(&["address"], field_errors(&[("zip", "regex")])).unwrap();
+    errors.add_nested_at("items", list(vec![(3, field_errors(&[("id", "range")]))])).unwrap();
+
+    let errors = errors.map_errors(|field, mut error| {
+        error.message = Some(Cow::from(format!("{} is invalid", field)));
+        error
+    });
+
+    assert_eq!(errors.field_errors()["name"][0].message, Some(Cow::from("name is invalid")));
+    assert_eq!(
+        nested(&errors, "address").field_errors()["zip"][0].message,
+        Some(Cow::from("zip is invalid"))
+    );
+    match errors.errors()["items"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&3].field_errors()["id"][0].message, Some(Cow::from("id is invalid")));
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[test]
+fn test_dynamic_field_names() {
+    let mut errors = ValidationErrors::new();
+    for header in ["first name", "e-mail"] {
+        errors.add(header.to_string(), ValidationError::new("required"));
+    }
+    errors.add(Cow::from("e-mail"), ValidationError::new("email"));
+    let column = format!("column {}", 3);
+    errors.merge_at(std::slice::from_ref(&column), field_errors(&[("value", "range")])).unwrap();
+
+    let errs = errors.field_errors();
+    assert_eq!(errs["first name"][0].code, "required");
+    assert_eq!(errs["e-mail"].len(), 2);
+    assert!(ValidationErrors::has_error(&Err(errors.clone()), &column));
+    assert_eq!(nested(&errors, "column 3").field_errors()["value"][0].code, "range");
+
+    let result = ValidationErrors::merge(Ok(()), column, Err(field_errors(&[("other", "x")])));
+    assert!(ValidationErrors::has_error(&result, "column 3"));
+}
+
+fn all_kinds() -> ValidationErrors {
+    let mut email = ValidationError::new("email");
+    email.message = Some(Cow::from("invalid email"));
+    email.add_param(Cow::from("value"), "bob");
+
+    let mut errors = field_errors(&[("name", "length"), ("name", "regex")]);
+    errors.merge_at(&["address"], field_errors(&[("zip", "regex")])).unwrap();
+    let mut contact = ValidationErrors::new();
+    contact.add("email", email);
+    errors
+        .add_nested_at("contacts", list(vec![(0, contact), (3, field_errors(&[("a", "b")]))]))
+        .unwrap();
+    errors.add("__all__", ValidationError::new("schema"));
+    errors
+}
+
+#[test]
+fn test_deserialize_round_trip() {
+    let errors = all_kinds();
+    let json = serde_json::to_string(&errors).unwrap();
+    let deserialized: ValidationErrors = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, errors);
+    // in the same order
+    assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+}
+
+#[test]
+fn test_deserialize_kinds_from_their_shape() {
+    let errors: ValidationErrors = serde_json::from_str(
+        r#"{
+            "name": [{ "code": "length", "message": null, "params": { "min": 2 } }],
+            "address": { "zip": [{ "code": "regex", "params": {} }] },
+            "contacts": { "1": { "email": [{ "code": "email", "params": {} }] } }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(errors.field_errors()["name"][0].params["min"], 2);
+    assert_eq!(nested(&errors, "address").field_errors()["zip"][0].code, "regex");
+    match errors.errors()["contacts"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&1].field_errors()["email"][0].code, "email");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+
+    assert!(serde_json::from_str::<ValidationErrors>(r#"{ "name": 1 }"#).is_err());
+    assert!(serde_json::from_str::<ValidationErrors>(r#"{ "name": [{ "params": {} }] }"#).is_err());
+}
+
+#[test]
+fn test_tagged_round_trip() {
+    #[derive(Debug, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+    struct Response {
+        #[serde(with = "validator::tagged")]
+        errors: ValidationErrors,
+    }
+
+    // nested structs with numeric names can't be told from a list without the tags
+    let mut errors = all_kinds();
+    errors.merge_at(&["columns"], field_errors(&[("1", "required"), ("2", "range")])).unwrap();
+    let untagged: ValidationErrors =
+        serde_json::from_str(&serde_json::to_string(&errors).unwrap()).unwrap();
+    assert_eq!(untagged, errors);
+    errors.merge_at(&["rows", "1"], field_errors(&[("id", "range")])).unwrap();
+    let untagged: ValidationErrors =
+        serde_json::from_str(&serde_json::to_string(&errors).unwrap()).unwrap();
+    assert_ne!(untagged, errors);
+
+    let response = Response { errors };
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["errors"]["name"]["field"][0]["code"], "length");
+    assert_eq!(json["errors"]["address"]["struct"]["zip"]["field"][0]["code"], "regex");
+    assert_eq!(json["errors"]["contacts"]["list"]["3"]["a"]["field"][0]["code"], "b");
+    assert_eq!(json["errors"]["columns"]["struct"]["1"]["field"][0]["code"], "required");
+    assert_eq!(json["errors"]["rows"]["struct"]["1"]["struct"]["id"]["field"][0]["code"], "range");
+
+    let deserialized: Response = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized, response);
+}
+
+#[test]
+fn test_warnings() {
+    let mut errors = field_errors(&[("name", "length")]);
+    errors.add("name", ValidationError::warning("regex"));
+    errors.merge_at(&["address"], field_errors(&[("zip", "regex")])).unwrap();
+    let mut warnings = ValidationErrors::new();
+    warnings.add("street", ValidationError::warning("length"));
+    errors.add_nested_at("items", list(vec![(1, warnings)])).unwrap();
+    assert!(errors.has_errors());
+
+    let (errs, warnings) = errors.clone().split_warnings();
+    assert_eq!(errs.field_errors()["name"].len(), 1);
+    assert_eq!(errs.field_errors()["name"][0].code, "length");
+    assert!(!errs.errors().contains_key("items"));
+    assert!(!warnings.has_errors());
+    assert_eq!(warnings.field_errors()["name"][0].code, "regex");
+    assert!(!warnings.errors().contains_key("address"));
+    assert_eq!(warnings, errors.warnings());
+
+    // warnings don't count as errors of the field
+    let mut warnings = ValidationErrors::new();
+    warnings.add("name", ValidationError::warning("length"));
+    assert!(!ValidationErrors::has_error(&Err(warnings.clone()), "name"));
+    assert!(ValidationErrors::has_error(&Err(errors), "name"));
+
+    assert_eq!(ValidationErrors::with_warnings(Ok(ValidationErrors::new())), Ok(()));
+    assert_eq!(ValidationErrors::with_warnings(Ok(warnings.clone())), Err(warnings));
+}
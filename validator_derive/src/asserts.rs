@@ -110,6 +110,51 @@ pub fn assert_has_len(field_name: String, type_name: &str, field_type: &syn::Typ
     }
 }
 
+/// Whether the type is `String`, `str`, `Cow<str>` or `Box<str>`, whatever their path and lifetime,
+/// maybe behind references and in options
+fn is_str_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(syn::TypeReference { elem, .. })
+        | syn::Type::Group(syn::TypeGroup { elem, .. })
+        | syn::Type::Paren(syn::TypeParen { elem, .. }) => is_str_type(elem),
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            let segment = match path.segments.last() {
+                Some(segment) => segment,
+                None => return false,
+            };
+            let type_args = match segment.arguments {
+                syn::PathArguments::AngleBracketed(ref args) => args
+                    .args
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            };
+            match (segment.ident.to_string().as_str(), type_args.as_slice()) {
+                ("String", []) | ("str", []) => true,
+                ("Option", [inner]) => is_str_type(inner),
+                ("Cow", [inner]) | ("Box", [inner]) => {
+                    matches!(inner, syn::Type::Path(p) if p.qself.is_none() && p.path.is_ident("str"))
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+pub fn assert_has_str_len(field_name: String, type_name: &str, field_type: &syn::Type) {
+    if !is_str_type(field_type) {
+        abort!(field_type.span(),
+                "Validator `length` with a `mode` can only be used on types `String`, `&str`, Cow<'_,str>, Box<str> or an Option of those but found `{}` for field `{}`",
+                type_name, field_name
+            );
+    }
+}
+
 pub fn assert_has_range(field_name: String, type_name: &str, field_type: &syn::Type) {
     if !NUMBER_TYPES.contains(&type_name) {
         abort!(
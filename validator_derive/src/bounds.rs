@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+
+use if_chain::if_chain;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::visit::Visit;
+use syn::{parse_quote, Type, WherePredicate};
+
+use validator_types::{CustomArgument, Validator};
+
+use crate::validation::FieldInformation;
+
+/// The bounds needed by the validations of the fields whose type uses a type parameter of the
+/// struct, so `Page<T>` with `#[validate(nested)] items: Vec<T>` doesn't need a bound on `T`.
+/// Each predicate comes along with whether it uses the `'v_a` lifetime of `ValidateArgs`, the
+/// other impls need them to hold for any lifetime.
+pub fn infer_bounds(
+    generics: &syn::Generics,
+    fields: &[FieldInformation],
+) -> Vec<(WherePredicate, bool)> {
+    let params = generics.type_params().map(|param| param.ident.clone()).collect::<HashSet<_>>();
+    if params.is_empty() {
+        return vec![];
+    }
+
+    let mut bounds = vec![];
+    for field in fields.iter().filter(|field| mentions_param(&field.field.ty, &params)) {
+        let ty = &field.field.ty;
+        let value = strip_options(ty);
+        let is_param = is_param(value, &params);
+        for validation in &field.validations {
+            let mut validation_bounds = vec![];
+            match validation.validator {
+                Validator::Nested { ref argument, .. } => {
+                    let args = match **argument {
+                        Some(CustomArgument { ref arg_type, .. }) => quote!(#arg_type),
+                        None => quote!(()),
+                    };
+                    validation_bounds.push((
+                        parse_quote!(#ty: ::validator::ValidateNested<'v_a, Args = #args>),
+                        true,
+                    ));
+                }
+                // The items are not added to the errors
+                Validator::Unique { .. } => continue,
+                Validator::MustMatch(_) => validation_bounds.push((
+                    parse_quote!(#ty: ::std::cmp::Eq + ::validator::serde::Serialize),
+                    false,
+                )),
+                // Every other validator adds the value to the params of its errors, the length
+                // and range of a type parameter can't be checked by the derive so they are bounds
+                ref validator => {
+                    match validator {
+                        Validator::Length { .. } if is_param => validation_bounds.push((
+                            parse_quote!(for<'v_h> &'v_h #value: ::validator::HasLen),
+                            false,
+                        )),
+                        Validator::Range { .. } if is_param => validation_bounds.push((
+                            parse_quote!(#value: ::std::convert::Into<f64> + ::std::marker::Copy),
+                            false,
+                        )),
+                        _ => (),
+                    }
+                    validation_bounds
+                        .push((parse_quote!(#value: ::validator::serde::Serialize), false));
+                }
+            }
+            for bound in validation_bounds {
+                if !bounds.contains(&bound) {
+                    bounds.push(bound);
+                }
+            }
+        }
+    }
+
+    bounds
+}
+
+/// Whether the field type, once unwrapped from its options, is a type parameter of the struct
+pub fn is_type_param(ty: &Type, generics: &syn::Generics) -> bool {
+    let params = generics.type_params().map(|param| param.ident.clone()).collect::<HashSet<_>>();
+    is_param(strip_options(ty), &params)
+}
+
+/// Whether the predicate uses the `'v_a` lifetime of `ValidateArgs`
+pub fn uses_args_lifetime(predicate: &WherePredicate) -> bool {
+    struct FindLifetime(bool);
+
+    impl<'ast> Visit<'ast> for FindLifetime {
+        fn visit_lifetime(&mut self, lifetime: &'ast syn::Lifetime) {
+            self.0 |= lifetime.ident == "v_a";
+        }
+    }
+
+    let mut finder = FindLifetime(false);
+    finder.visit_where_predicate(predicate);
+    finder.0
+}
+
+fn is_param(ty: &Type, params: &HashSet<syn::Ident>) -> bool {
+    match ty {
+        Type::Path(syn::TypePath { qself: None, ref path }) => {
+            path.get_ident().map_or(false, |ident| params.contains(ident))
+        }
+        _ => false,
+    }
+}
+
+/// Whether one of the type parameters appears anywhere in the type
+fn mentions_param(ty: &Type, params: &HashSet<syn::Ident>) -> bool {
+    fn walk(tokens: TokenStream, params: &HashSet<syn::Ident>) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => params.contains(&ident),
+            TokenTree::Group(group) => walk(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    walk(ty.to_token_stream(), params)
+}
+
+/// The type of the value given to the validators, optional fields are unwrapped first
+fn strip_options(ty: &Type) -> &Type {
+    if_chain! {
+        if let Type::Path(syn::TypePath { qself: None, ref path }) = ty;
+        if let Some(segment) = path.segments.last();
+        if segment.ident == "Option";
+        if let syn::PathArguments::AngleBracketed(ref args) = segment.arguments;
+        if let Some(syn::GenericArgument::Type(ref inner)) = args.args.first();
+        then {
+            strip_options(inner)
+        } else {
+            ty
+        }
+    }
+}
@@ -7,19 +7,27 @@ use proc_macro2::Span;
 use proc_macro_error::{abort, proc_macro_error};
 use quote::ToTokens;
 use quote::{quote, quote_spanned};
-use syn::{parse_quote, spanned::Spanned, GenericParam, Lifetime, LifetimeDef, Type};
-
-use asserts::{assert_has_len, assert_has_range, assert_string_type, assert_type_matches};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_quote, spanned::Spanned, GenericParam, Lifetime, LifetimeDef, Token, Type, WherePredicate,
+};
+
+use asserts::{
+    assert_has_len, assert_has_range, assert_has_str_len, assert_string_type, assert_type_matches,
+};
 use lit::*;
 use quoting::{quote_schema_validations, quote_validator, FieldQuoter};
 use validation::*;
-use validator_types::{CustomArgument, Validator};
+use validator_types::{CharClass, CustomArgument, EmptyCheck, Validator, ValueOrPath};
 
 use crate::asserts::assert_custom_arg_type;
 
 mod asserts;
+mod bounds;
 mod lit;
 mod quoting;
+mod sanitize;
 mod validation;
 
 #[proc_macro_derive(Validate, attributes(validate))]
@@ -29,19 +37,68 @@ pub fn derive_validation(input: proc_macro::TokenStream) -> proc_macro::TokenStr
     impl_validate(&ast).into()
 }
 
+// `#[sanitize]` is reserved by rustc so the fields use `#[normalize]`
+#[proc_macro_derive(Sanitize, attributes(normalize))]
+#[proc_macro_error]
+pub fn derive_sanitize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+    sanitize::impl_sanitize(&ast).into()
+}
+
 fn impl_validate(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     // Collecting the validators
     let mut fields_validations = collect_field_validations(ast);
     let mut struct_validations = find_struct_validations(&ast.attrs);
-    let (arg_type, has_arg) =
-        construct_validator_argument_type(&mut fields_validations, &mut struct_validations);
-    let (validations, nested_validations) = quote_field_validations(fields_validations);
+    let context = find_struct_context(&ast.attrs);
+    let (arg_type, has_arg) = construct_validator_argument_type(
+        &mut fields_validations,
+        &mut struct_validations,
+        context.as_ref(),
+    );
+    // The given bounds replace the inferred ones, in every impl
+    let bounds = match find_struct_bound(&ast.attrs) {
+        Some(predicates) => predicates
+            .into_iter()
+            .map(|predicate| {
+                let uses_lifetime = bounds::uses_args_lifetime(&predicate);
+                (predicate, uses_lifetime)
+            })
+            .collect(),
+        None => bounds::infer_bounds(&ast.generics, &fields_validations),
+    };
+    let field_names = fields_validations.iter().map(|f| f.name.clone()).collect::<Vec<_>>();
+    let (validations, nested_validations) =
+        quote_field_validations(fields_validations, &ast.ident, &ast.generics);
 
     let schema_validations = quote_schema_validations(&struct_validations);
 
+    // Nested errors are merged last, the fields are put back in their declaration order
+    let sort_fields = if nested_validations.is_empty() {
+        quote!()
+    } else {
+        quote!(
+            if let ::std::result::Result::Err(ref mut errors) = result {
+                errors.sort_fields(&[#(#field_names),*]);
+            }
+        )
+    };
+
     // Struct specific definitions
     let ident = &ast.ident;
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    // Outside of `ValidateArgs`, the bounds using its lifetime have to hold for any of them
+    let mut bounded_generics = ast.generics.clone();
+    bounded_generics.make_where_clause().predicates.extend(bounds.iter().map(
+        |(predicate, uses_lifetime)| match predicate {
+            WherePredicate::Type(predicate) if *uses_lifetime => {
+                WherePredicate::Type(syn::PredicateType {
+                    lifetimes: Some(parse_quote!(for<'v_a>)),
+                    ..predicate.clone()
+                })
+            }
+            predicate => predicate.clone(),
+        },
+    ));
+    let (impl_generics, ty_generics, where_clause) = bounded_generics.split_for_impl();
 
     // The Validate trait implementation
     let validate_trait_impl = if !has_arg {
@@ -51,6 +108,33 @@ fn impl_validate(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
                     use ::validator::ValidateArgs;
                     self.validate_args(())
                 }
+
+                fn validate_with_warnings(
+                    &self,
+                ) -> ::std::result::Result<::validator::ValidationErrors, ::validator::ValidationErrors> {
+                    use ::validator::ValidateArgs;
+                    self.validate_args_with_warnings(())
+                }
+            }
+        )
+    } else {
+        quote!()
+    };
+
+    // The ValidateWith trait implementation, forwarded to ValidateArgs
+    let validate_with_trait_impl = if let Some(context) = &context {
+        quote!(
+            impl #impl_generics ::validator::ValidateWith<#context> for #ident #ty_generics #where_clause {
+                fn validate_with(&self, context: &#context) -> ::std::result::Result<(), ::validator::ValidationErrors> {
+                    ::validator::ValidateArgs::validate_args(self, context)
+                }
+
+                fn validate_with_context_and_warnings(
+                    &self,
+                    context: &#context,
+                ) -> ::std::result::Result<::validator::ValidationErrors, ::validator::ValidationErrors> {
+                    ::validator::ValidateArgs::validate_args_with_warnings(self, context)
+                }
             }
         )
     } else {
@@ -62,12 +146,52 @@ fn impl_validate(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     expanded_generic
         .params
         .insert(0, GenericParam::Lifetime(LifetimeDef::new(Lifetime::new("'v_a", ast.span()))));
+    expanded_generic
+        .make_where_clause()
+        .predicates
+        .extend(bounds.into_iter().map(|(predicate, _)| predicate));
+
+    let (impl_generics, _, where_clause) = expanded_generic.split_for_impl();
 
-    let (impl_generics, _, _) = expanded_generic.split_for_impl();
+    // The structs without arguments are nested through their `Validate` implementation, the
+    // ones with arguments also need an impl once boxed since `Box` can't forward generically
+    let validate_nested_trait_impl = if has_arg {
+        quote!(
+            #[allow(clippy::all)]
+            #[allow(single_use_lifetimes)]
+            impl #impl_generics ::validator::ValidateNested<'v_a> for #ident #ty_generics #where_clause {
+                type Args = #arg_type;
+
+                fn validate_nested(
+                    &self,
+                    args: Self::Args,
+                ) -> ::std::result::Result<(), ::validator::ValidationErrorsKind> {
+                    ::validator::validate_nested_args(self, args)
+                }
+            }
+
+            #[allow(clippy::all)]
+            #[allow(single_use_lifetimes)]
+            impl #impl_generics ::validator::ValidateNested<'v_a> for ::std::boxed::Box<#ident #ty_generics> #where_clause {
+                type Args = #arg_type;
+
+                fn validate_nested(
+                    &self,
+                    args: Self::Args,
+                ) -> ::std::result::Result<(), ::validator::ValidationErrorsKind> {
+                    ::validator::validate_nested_args(&**self, args)
+                }
+            }
+        )
+    } else {
+        quote!()
+    };
 
     // Implementing ValidateArgs
     let impl_ast = quote!(
         #validate_trait_impl
+        #validate_with_trait_impl
+        #validate_nested_trait_impl
 
         // We need this here to prevent formatting lints that can be caused by `quote_spanned!`
         // See: rust-lang/rust-clippy#6249 for more reference
@@ -78,9 +202,19 @@ fn impl_validate(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
         impl #impl_generics ::validator::ValidateArgs<'v_a> for #ident #ty_generics #where_clause {
             type Args = #arg_type;
 
+            fn validate_args(&self, args: Self::Args) -> ::std::result::Result<(), ::validator::ValidationErrors> {
+                match self.validate_args_with_warnings(args) {
+                    ::std::result::Result::Ok(_) => ::std::result::Result::Ok(()),
+                    ::std::result::Result::Err(errors) => ::std::result::Result::Err(errors.split_warnings().0),
+                }
+            }
+
             #[allow(unused_mut)]
             #[allow(unused_variable)]
-            fn validate_args(&self, args: Self::Args) -> ::std::result::Result<(), ::validator::ValidationErrors> {
+            fn validate_args_with_warnings(
+                &self,
+                args: Self::Args,
+            ) -> ::std::result::Result<::validator::ValidationErrors, ::validator::ValidationErrors> {
                 let mut errors = ::validator::ValidationErrors::new();
 
                 #(#validations)*
@@ -94,7 +228,14 @@ fn impl_validate(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
                 };
 
                 #(#nested_validations)*
-                result
+                #sort_fields
+
+                // The validation only fails if there is an error that is not a warning
+                match result {
+                    ::std::result::Result::Ok(()) => ::std::result::Result::Ok(::validator::ValidationErrors::new()),
+                    ::std::result::Result::Err(errors) if errors.has_errors() => ::std::result::Result::Err(errors),
+                    ::std::result::Result::Err(warnings) => ::std::result::Result::Ok(warnings),
+                }
             }
         }
     );
@@ -126,7 +267,7 @@ fn collect_field_validations(ast: &syn::DeriveInput) -> Vec<FieldInformation> {
     let field_types = find_fields_type(&fields);
     fields.drain(..).fold(vec![], |mut acc, field| {
         let key = field.ident.clone().unwrap().to_string();
-        let (name, validations) = find_validators_for_field(&field, &field_types);
+        let (name, validations) = find_validators_for_field(&field, &field_types, &ast.generics);
         acc.push(FieldInformation::new(
             field,
             field_types.get(&key).unwrap().clone(),
@@ -140,66 +281,131 @@ fn collect_field_validations(ast: &syn::DeriveInput) -> Vec<FieldInformation> {
 fn construct_validator_argument_type(
     fields_validations: &mut [FieldInformation],
     struct_validations: &mut [SchemaValidation],
+    context: Option<&Type>,
 ) -> (proc_macro2::TokenStream, bool) {
     const ARGS_PARAMETER_NAME: &str = "args";
 
-    // This iterator only holds custom validations with a argument_type
-    let mut customs: Vec<&mut CustomArgument> = fields_validations
-        .iter_mut()
-        .flat_map(|x| {
-            x.validations.iter_mut().filter_map(|x| x.validator.get_custom_argument_mut())
-        })
-        .collect();
+    // With a context, the only argument is a reference to it and every function is given it
+    if let Some(context) = context {
+        let span = context.span();
+        let arg_type: Type = parse_quote!(&'v_a #context);
+        let mut context_argument = CustomArgument::new(span, arg_type.clone());
+        context_argument.arg_access = Some(syn::parse_str(ARGS_PARAMETER_NAME).unwrap());
+
+        let arguments = fields_validations
+            .iter_mut()
+            .flat_map(|x| x.validations.iter_mut())
+            .filter_map(|x| match x.validator {
+                Validator::Custom { ref mut argument, .. } => Some(argument),
+                Validator::Nested { ref mut argument, context: true, .. } => Some(argument),
+                Validator::Nested { ref argument, context: false, .. } => {
+                    assert_no_argument_with_context(argument);
+                    None
+                }
+                _ => None,
+            });
+        for argument in arguments {
+            assert_no_argument_with_context(argument);
+            **argument = Some(context_argument.clone());
+        }
+        for schema in struct_validations.iter_mut() {
+            assert_no_argument_with_context(&schema.args);
+            schema.args = Some(context_argument.clone());
+        }
 
-    let mut schemas: Vec<&mut CustomArgument> =
-        struct_validations.iter_mut().filter_map(|x| x.args.as_mut()).collect();
+        return (quote_spanned!(span=> #arg_type), true);
+    }
 
-    customs.append(&mut schemas);
+    for field in fields_validations.iter() {
+        if field
+            .validations
+            .iter()
+            .any(|x| matches!(x.validator, Validator::Nested { context: true, .. }))
+        {
+            abort!(
+                field.field.span(),
+                "`nested(context)` on field `{}` requires a `#[validate(context = \"...\")]` on the struct",
+                field.name
+            );
+        }
+    }
+
+    // The custom and schema arguments each take a slot of the arguments, the nested ones share
+    // the slot of the first argument of the same type so the parent's arguments are given once
+    let mut customs: Vec<&mut CustomArgument> = vec![];
+    let mut nested: Vec<&mut CustomArgument> = vec![];
+    for validation in fields_validations.iter_mut().flat_map(|x| x.validations.iter_mut()) {
+        let is_nested = matches!(validation.validator, Validator::Nested { .. });
+        if let Some(argument) = validation.validator.get_custom_argument_mut() {
+            if is_nested {
+                nested.push(argument);
+            } else {
+                customs.push(argument);
+            }
+        }
+    }
+    customs.extend(struct_validations.iter_mut().filter_map(|x| x.args.as_mut()));
+
+    let mut slots: Vec<(Type, Span)> = vec![];
+    let mut arguments: Vec<(&mut CustomArgument, usize)> = vec![];
+    for arg in customs {
+        slots.push((arg.arg_type.clone(), arg.def_span));
+        arguments.push((arg, slots.len() - 1));
+    }
+    for arg in nested {
+        let slot = match slots.iter().position(|(arg_type, _)| *arg_type == arg.arg_type) {
+            Some(slot) => slot,
+            None => {
+                slots.push((arg.arg_type.clone(), arg.def_span));
+                slots.len() - 1
+            }
+        };
+        arguments.push((arg, slot));
+    }
 
-    if customs.is_empty() {
+    if slots.is_empty() {
         // Just the default empty type if no types are defined
         (quote!(()), false)
-    } else if customs.len() == 1 {
+    } else if slots.len() == 1 {
         // A single parameter will not be wrapped in a tuple
-        let arg = customs.pop().unwrap();
-        arg.arg_access = Some(syn::parse_str(ARGS_PARAMETER_NAME).unwrap());
+        for (arg, _) in arguments {
+            arg.arg_access = Some(syn::parse_str(ARGS_PARAMETER_NAME).unwrap());
+        }
 
-        let type_stream: &Type = &arg.arg_type;
-        let span = arg.def_span;
-        (quote_spanned!(span=> #type_stream), true)
+        let (type_stream, span) = &slots[0];
+        (quote_spanned!(*span=> #type_stream), true)
     } else {
         // Multiple times will be wrapped in a tuple
-        let mut index = 0;
-        let params = customs.iter_mut().fold(quote!(), |acc, arg| {
-            let arg_access_string = format!("{}.{}", ARGS_PARAMETER_NAME, index);
+        for (arg, slot) in arguments {
+            let arg_access_string = format!("{}.{}", ARGS_PARAMETER_NAME, slot);
             arg.arg_access = Some(syn::parse_str(arg_access_string.as_str()).unwrap());
-            index += 1;
-
-            let type_stream: &Type = &arg.arg_type;
-            let span = arg.def_span;
-
-            if index == 1 {
-                quote_spanned!(span=> #type_stream)
-            } else {
-                quote_spanned!(span=> #acc, #type_stream)
-            }
-        });
+        }
 
-        (quote!((#params)), true)
+        let params = slots.iter().map(|(type_stream, span)| quote_spanned!(*span=> #type_stream));
+        (quote!((#(#params),*)), true)
     }
 }
 
 fn quote_field_validations(
     mut fields: Vec<FieldInformation>,
+    struct_ident: &syn::Ident,
+    generics: &syn::Generics,
 ) -> (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) {
     let mut validations = vec![];
     let mut nested_validations = vec![];
 
     fields.drain(..).for_each(|x| {
         let field_ident = x.field.ident.clone().unwrap();
-        let field_quoter = FieldQuoter::new(field_ident, x.name, x.field_type);
-
-        for validation in &x.validations {
+        let type_param = bounds::is_type_param(&x.field.ty, generics);
+        let field_quoter =
+            FieldQuoter::new(struct_ident.clone(), field_ident, x.name, x.field_type, type_param);
+
+        // `unique` runs last since the errors of the field can't be added to its list of errors
+        let (unique, others): (Vec<_>, Vec<_>) = x
+            .validations
+            .iter()
+            .partition(|validation| matches!(validation.validator, Validator::Unique { .. }));
+        for validation in others.into_iter().chain(unique) {
             quote_validator(&field_quoter, validation, &mut validations, &mut nested_validations);
         }
     });
@@ -227,6 +433,7 @@ fn find_struct_validation(attr: &syn::Attribute) -> SchemaValidation {
             let mut skip_on_field_errors = true;
             let mut code = None;
             let mut message = None;
+            let mut warning = false;
             let mut args = None;
 
             for arg in nested {
@@ -264,6 +471,14 @@ fn find_struct_validation(attr: &syn::Attribute) -> SchemaValidation {
                                     : only a string is allowed"),
                                 };
                             },
+                            "severity" => {
+                                warning = match lit_to_string(lit).as_deref() {
+                                    Some("warning") => true,
+                                    Some("error") => false,
+                                    _ => error(lit.span(), "invalid argument for `severity` \
+                                    : only \"error\" or \"warning\" are allowed"),
+                                };
+                            },
                             "arg" => {
                                 match lit_to_string(lit) {
                                     Some(s) => {
@@ -300,6 +515,7 @@ fn find_struct_validation(attr: &syn::Attribute) -> SchemaValidation {
                 skip_on_field_errors,
                 code,
                 message,
+                warning,
             }
         } else {
             error(attr.span(), "Unexpected struct validator")
@@ -307,15 +523,98 @@ fn find_struct_validation(attr: &syn::Attribute) -> SchemaValidation {
     }
 }
 
+/// Aborts if a function has its own `arg` while the struct has a context, which is the only
+/// argument of `validate_args` then
+fn assert_no_argument_with_context(argument: &Option<CustomArgument>) {
+    if let Some(argument) = argument {
+        abort!(
+            argument.def_span,
+            "`arg` can't be used on a struct with a `context`: the functions are given the context"
+        );
+    }
+}
+
 /// Finds all struct schema validations
 fn find_struct_validations(struct_attrs: &[syn::Attribute]) -> Vec<SchemaValidation> {
     struct_attrs
         .iter()
         .filter(|attribute| attribute.path == parse_quote!(validate))
+        .filter(|attribute| {
+            !is_struct_option(attribute, "context") && !is_struct_option(attribute, "bound")
+        })
         .map(find_struct_validation)
         .collect()
 }
 
+/// Whether it is a `#[validate(<name> = "...")]` attribute, like `context`
+fn is_struct_option(attr: &syn::Attribute, name: &str) -> bool {
+    if_chain! {
+        if attr.path == parse_quote!(validate);
+        if let Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) = attr.parse_meta();
+        if let Some(syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { ref path, .. }))) = nested.first();
+        then {
+            path.is_ident(name)
+        } else {
+            false
+        }
+    }
+}
+
+/// The type of the context given with `#[validate(context = "...")]`, if any
+fn find_struct_context(struct_attrs: &[syn::Attribute]) -> Option<Type> {
+    let mut context = None;
+
+    for attr in struct_attrs.iter().filter(|attribute| is_struct_option(attribute, "context")) {
+        if context.is_some() {
+            abort!(attr.span(), "Invalid context: only one `context` can be defined");
+        }
+
+        if_chain! {
+            if let Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) = attr.parse_meta();
+            if nested.len() == 1;
+            if let syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { ref lit, .. })) = nested[0];
+            then {
+                match lit_to_string(lit).and_then(|s| syn::parse_str::<Type>(&s).ok()) {
+                    Some(ty) => context = Some(ty),
+                    None => abort!(lit.span(), "Invalid context: `context` has to be a type, eg `context = \"MyContext\"`"),
+                }
+            } else {
+                abort!(attr.span(), "Invalid context: `context` has to be alone in its attribute, eg `#[validate(context = \"MyContext\")]`");
+            }
+        }
+    }
+
+    context
+}
+
+/// The where predicates given with `#[validate(bound = "...")]`, replacing the inferred ones
+fn find_struct_bound(struct_attrs: &[syn::Attribute]) -> Option<Vec<WherePredicate>> {
+    let mut bound = None;
+
+    for attr in struct_attrs.iter().filter(|attribute| is_struct_option(attribute, "bound")) {
+        if bound.is_some() {
+            abort!(attr.span(), "Invalid bound: only one `bound` can be defined");
+        }
+
+        if_chain! {
+            if let Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) = attr.parse_meta();
+            if nested.len() == 1;
+            if let syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { ref lit, .. })) = nested[0];
+            then {
+                let parser = Punctuated::<WherePredicate, Token![,]>::parse_terminated;
+                match lit_to_string(lit).and_then(|s| parser.parse_str(&s).ok()) {
+                    Some(predicates) => bound = Some(predicates.into_iter().collect()),
+                    None => abort!(lit.span(), "Invalid bound: `bound` has to be where predicates, eg `bound = \"T: MyTrait\"`"),
+                }
+            } else {
+                abort!(attr.span(), "Invalid bound: `bound` has to be alone in its attribute, eg `#[validate(bound = \"T: MyTrait\")]`");
+            }
+        }
+    }
+
+    bound
+}
+
 /// Find the types (as string) for each field of the struct
 /// Needed for the `must_match` filter
 fn find_fields_type(fields: &[syn::Field]) -> HashMap<String, String> {
@@ -362,6 +661,7 @@ fn find_fields_type(fields: &[syn::Field]) -> HashMap<String, String> {
 fn find_validators_for_field(
     field: &syn::Field,
     field_types: &HashMap<String, String>,
+    generics: &syn::Generics,
 ) -> (String, Vec<FieldValidation>) {
     let rust_ident = field.ident.clone().unwrap().to_string();
     let mut field_ident = field.ident.clone().unwrap().to_string();
@@ -376,6 +676,8 @@ fn find_validators_for_field(
     };
 
     let field_type = field_types.get(&field_ident).unwrap();
+    // The length and range of a type parameter are checked by the bounds inferred for it instead
+    let type_param = bounds::is_type_param(&field.ty, generics);
 
     let mut validators = vec![];
     let mut has_validate = false;
@@ -404,7 +706,7 @@ fn find_validators_for_field(
                 for meta_item in meta_items {
                     match *meta_item {
                         syn::NestedMeta::Meta(ref item) => match *item {
-                            // email, url, phone, credit_card, non_control_character
+                            // email, url, hostname, domain, phone, credit_card, non_control_character
                             syn::Meta::Path(ref name) => {
                                 match name.get_ident().unwrap().to_string().as_ref() {
                                     "email" => {
@@ -415,6 +717,17 @@ fn find_validators_for_field(
                                         assert_string_type("url", field_type, &field.ty);
                                         validators.push(FieldValidation::new(Validator::Url));
                                     }
+                                    "hostname" => {
+                                        assert_string_type("hostname", field_type, &field.ty);
+                                        validators.push(FieldValidation::new(Validator::Hostname));
+                                    }
+                                    "domain" => {
+                                        assert_string_type("domain", field_type, &field.ty);
+                                        validators.push(FieldValidation::new(Validator::Domain {
+                                            wildcard: false,
+                                            public_suffix: false,
+                                        }));
+                                    }
                                     #[cfg(feature = "phone")]
                                     "phone" => {
                                         assert_string_type("phone", field_type, &field.ty);
@@ -438,11 +751,57 @@ fn find_validators_for_field(
                                         ));
                                     }
                                     "required" => {
-                                        validators.push(FieldValidation::new(Validator::Required));
+                                        validators.push(FieldValidation::new(
+                                            Validator::Required { empty_check: None },
+                                        ));
+                                    }
+                                    "unique" => {
+                                        validators.push(FieldValidation::new(Validator::Unique {
+                                            by: None,
+                                        }));
+                                    }
+                                    "non_empty" | "not_blank" => {
+                                        let validator_name = name.get_ident().unwrap().to_string();
+                                        let check = EmptyCheck::from_name(&validator_name).unwrap();
+                                        if check == EmptyCheck::NotBlank {
+                                            assert_string_type(
+                                                &validator_name,
+                                                field_type,
+                                                &field.ty,
+                                            );
+                                        }
+                                        validators.push(FieldValidation::new(
+                                            Validator::EmptyCheck(check),
+                                        ));
+                                    }
+                                    "ascii" | "alphanumeric" | "alpha" | "numeric"
+                                    | "lowercase" | "uppercase" | "printable" => {
+                                        let validator_name = name.get_ident().unwrap().to_string();
+                                        assert_string_type(&validator_name, field_type, &field.ty);
+                                        validators.push(FieldValidation::new(
+                                            Validator::CharClass {
+                                                class: CharClass::from_name(&validator_name)
+                                                    .unwrap(),
+                                                ascii_only: false,
+                                            },
+                                        ));
                                     }
                                     "required_nested" => {
-                                        validators.push(FieldValidation::new(Validator::Required));
-                                        validators.push(FieldValidation::new(Validator::Nested));
+                                        validators.push(FieldValidation::new(
+                                            Validator::Required { empty_check: None },
+                                        ));
+                                        validators.push(FieldValidation::new(Validator::Nested {
+                                            argument: Box::new(None),
+                                            context: false,
+                                            max_depth: None,
+                                        }));
+                                    }
+                                    "nested" => {
+                                        validators.push(FieldValidation::new(Validator::Nested {
+                                            argument: Box::new(None),
+                                            context: false,
+                                            max_depth: None,
+                                        }));
                                     }
                                     _ => {
                                         let mut ident = proc_macro2::TokenStream::new();
@@ -462,25 +821,53 @@ fn find_validators_for_field(
                                             Some(s) => validators.push(FieldValidation::new(Validator::Custom {
                                                 function: s,
                                                 argument: Box::new(None),
+                                                with_parent: false,
                                             })),
                                             None => error(lit.span(), "invalid argument for `custom` validator: only strings are allowed"),
                                         };
                                     }
                                     "contains" => {
-                                        match lit_to_string(lit) {
-                                            Some(s) => validators.push(FieldValidation::new(Validator::Contains(s))),
-                                            None => error(lit.span(), "invalid argument for `contains` validator: only strings are allowed"),
-                                        };
+                                        validators.push(FieldValidation::new(Validator::Contains(
+                                            ValueOrPath::Value(lit.clone()),
+                                        )));
                                     }
                                     "does_not_contain" => {
-                                        match lit_to_string(lit) {
-                                            Some(s) => validators.push(FieldValidation::new(Validator::DoesNotContain(s))),
-                                            None => error(lit.span(), "invalid argument for `does_not_contain` validator: only strings are allowed"),
+                                        validators.push(FieldValidation::new(
+                                            Validator::DoesNotContain(ValueOrPath::Value(
+                                                lit.clone(),
+                                            )),
+                                        ));
+                                    }
+                                    "starts_with" | "ends_with" => {
+                                        let name = ident.to_string();
+                                        assert_string_type(&name, field_type, &field.ty);
+                                        let needles = match lit_to_string(lit) {
+                                            Some(s) => vec![s],
+                                            None => error(lit.span(), &format!("invalid argument for `{}` validator: only strings are allowed", name)),
                                         };
+                                        let validator = if name == "starts_with" {
+                                            Validator::StartsWith {
+                                                needles,
+                                                any: false,
+                                                case_insensitive: false,
+                                            }
+                                        } else {
+                                            Validator::EndsWith {
+                                                needles,
+                                                any: false,
+                                                case_insensitive: false,
+                                            }
+                                        };
+                                        validators.push(FieldValidation::new(validator));
                                     }
                                     "regex" => {
                                         match lit_to_string(lit) {
-                                            Some(s) => validators.push(FieldValidation::new(Validator::Regex(s))),
+                                            Some(s) => validators.push(FieldValidation::new(Validator::Regex {
+                                                regex: ValueOrPath::Path(s),
+                                                negate: false,
+                                                case_insensitive: false,
+                                                set: None,
+                                            })),
                                             None => error(lit.span(), "invalid argument for `regex` validator: only strings are allowed"),
                                         };
                                     }
@@ -506,15 +893,39 @@ fn find_validators_for_field(
                                 let ident = path.get_ident().unwrap();
                                 match ident.to_string().as_ref() {
                                     "length" => {
-                                        assert_has_len(rust_ident.clone(), field_type, &field.ty);
-                                        validators.push(extract_length_validation(
+                                        if !type_param {
+                                            assert_has_len(
+                                                rust_ident.clone(),
+                                                field_type,
+                                                &field.ty,
+                                            );
+                                        }
+                                        let validation = extract_length_validation(
                                             rust_ident.clone(),
                                             attr,
                                             &meta_items,
-                                        ));
+                                        );
+                                        let has_mode = matches!(
+                                            validation.validator,
+                                            Validator::Length { mode: Some(_), .. }
+                                        );
+                                        if has_mode && !type_param {
+                                            assert_has_str_len(
+                                                rust_ident.clone(),
+                                                field_type,
+                                                &field.ty,
+                                            );
+                                        }
+                                        validators.push(validation);
                                     }
                                     "range" => {
-                                        assert_has_range(rust_ident.clone(), field_type, &field.ty);
+                                        if !type_param {
+                                            assert_has_range(
+                                                rust_ident.clone(),
+                                                field_type,
+                                                &field.ty,
+                                            );
+                                        }
                                         validators.push(extract_range_validation(
                                             rust_ident.clone(),
                                             attr,
@@ -528,31 +939,106 @@ fn find_validators_for_field(
                                             &meta_items,
                                         ));
                                     }
+                                    "domain" => {
+                                        assert_string_type("domain", field_type, &field.ty);
+                                        validators.push(extract_domain_validation(
+                                            rust_ident.clone(),
+                                            &meta_items,
+                                        ));
+                                    }
                                     "email"
                                     | "url"
+                                    | "hostname"
                                     | "phone"
                                     | "credit_card"
                                     | "non_control_character"
-                                    | "required" => {
+                                    | "non_empty" => {
                                         validators.push(extract_argless_validation(
                                             ident.to_string(),
                                             rust_ident.clone(),
                                             &meta_items,
                                         ));
                                     }
+                                    "ascii" | "alphanumeric" | "alpha" | "numeric"
+                                    | "lowercase" | "uppercase" | "printable" => {
+                                        assert_string_type(
+                                            &ident.to_string(),
+                                            field_type,
+                                            &field.ty,
+                                        );
+                                        validators.push(extract_char_class_validation(
+                                            CharClass::from_name(&ident.to_string()).unwrap(),
+                                            rust_ident.clone(),
+                                            &meta_items,
+                                        ));
+                                    }
                                     "contains" | "does_not_contain" => {
-                                        validators.push(extract_one_arg_validation(
-                                            "pattern",
+                                        validators.push(extract_contains_validation(
+                                            &ident.to_string(),
+                                            rust_ident.clone(),
+                                            attr,
+                                            &meta_items,
+                                        ));
+                                    }
+                                    "starts_with" | "ends_with" => {
+                                        assert_string_type(
+                                            &ident.to_string(),
+                                            field_type,
+                                            &field.ty,
+                                        );
+                                        validators.push(extract_affix_validation(
+                                            &ident.to_string(),
+                                            rust_ident.clone(),
+                                            attr,
+                                            &meta_items,
+                                        ));
+                                    }
+                                    "not_blank" => {
+                                        assert_string_type("not_blank", field_type, &field.ty);
+                                        validators.push(extract_argless_validation(
                                             ident.to_string(),
                                             rust_ident.clone(),
                                             &meta_items,
                                         ));
                                     }
+                                    "required" => {
+                                        let validation = extract_required_validation(
+                                            rust_ident.clone(),
+                                            &meta_items,
+                                        );
+                                        if let Validator::Required {
+                                            empty_check: Some(EmptyCheck::NotBlank),
+                                        } = validation.validator
+                                        {
+                                            assert_string_type("required", field_type, &field.ty);
+                                        }
+                                        validators.push(validation);
+                                    }
+                                    "unique" => {
+                                        validators.push(extract_unique_validation(
+                                            rust_ident.clone(),
+                                            &meta_items,
+                                        ));
+                                    }
+                                    "one_of" | "none_of" => {
+                                        validators.push(extract_one_of_validation(
+                                            &ident.to_string(),
+                                            rust_ident.clone(),
+                                            attr,
+                                            &meta_items,
+                                        ));
+                                    }
+                                    "nested" => {
+                                        validators.push(extract_nested_validation(
+                                            rust_ident.clone(),
+                                            attr,
+                                            &meta_items,
+                                        ));
+                                    }
                                     "regex" => {
-                                        validators.push(extract_one_arg_validation(
-                                            "path",
-                                            ident.to_string(),
+                                        validators.push(extract_regex_validation(
                                             rust_ident.clone(),
+                                            attr,
                                             &meta_items,
                                         ));
                                     }
@@ -581,7 +1067,11 @@ fn find_validators_for_field(
                     };
                 }
             }
-            Ok(syn::Meta::Path(_)) => validators.push(FieldValidation::new(Validator::Nested)),
+            Ok(syn::Meta::Path(_)) => validators.push(FieldValidation::new(Validator::Nested {
+                argument: Box::new(None),
+                context: false,
+                max_depth: None,
+            })),
             Ok(syn::Meta::NameValue(_)) => abort!(attr.span(), "Unexpected name=value argument"),
             Err(e) => {
                 let error_string = format!("{:?}", e);
@@ -65,6 +65,19 @@ pub fn option_to_tokens<T: quote::ToTokens>(opt: &Option<T>) -> proc_macro2::Tok
     }
 }
 
+/// A reference to the needle of a contains-like validator. String literals are already a `&str`
+/// so they are left as is to be usable with the `str` needle of sets and maps of `String`.
+pub fn needle_to_tokens(needle: &ValueOrPath<syn::Lit>) -> proc_macro2::TokenStream {
+    match needle {
+        ValueOrPath::Value(syn::Lit::Str(ref s)) => quote!(#s),
+        ValueOrPath::Value(ref lit) => quote!(&#lit),
+        ValueOrPath::Path(ref path) => {
+            let ident: syn::Path = syn::parse_str(path).unwrap();
+            quote!(&#ident)
+        }
+    }
+}
+
 pub fn value_or_path_to_tokens<T>(value: &ValueOrPath<T>) -> proc_macro2::TokenStream
 where
     T: quote::ToTokens + std::clone::Clone + std::cmp::PartialEq + std::fmt::Debug,
@@ -2,25 +2,35 @@ use if_chain::if_chain;
 use proc_macro2::{self, Span};
 use quote::quote;
 
-use validator_types::Validator;
+use validator_types::{CustomArgument, EmptyCheck, RegexSetMode, Validator, ValueOrPath};
 
 use crate::asserts::{COW_TYPE, NUMBER_TYPES};
-use crate::lit::{option_to_tokens, value_or_path_to_tokens};
+use crate::lit::{needle_to_tokens, option_to_tokens, value_or_path_to_tokens};
 use crate::validation::{FieldValidation, SchemaValidation};
 
 /// Pass around all the information needed for creating a validation
 #[derive(Debug)]
 pub struct FieldQuoter {
+    /// The struct the field belongs to
+    struct_ident: syn::Ident,
     ident: syn::Ident,
     /// The field name
     name: String,
     /// The field type
     _type: String,
+    /// Whether the field type is a type parameter of the struct, maybe optional
+    type_param: bool,
 }
 
 impl FieldQuoter {
-    pub fn new(ident: syn::Ident, name: String, _type: String) -> FieldQuoter {
-        FieldQuoter { ident, name, _type }
+    pub fn new(
+        struct_ident: syn::Ident,
+        ident: syn::Ident,
+        name: String,
+        _type: String,
+        type_param: bool,
+    ) -> FieldQuoter {
+        FieldQuoter { struct_ident, ident, name, _type, type_param }
     }
 
     /// Don't put a & in front a pointer since we are going to pass
@@ -41,24 +51,32 @@ impl FieldQuoter {
         }
     }
 
-    pub fn quote_validator_field(&self) -> proc_macro2::TokenStream {
+    /// Always a reference to the field value, unlike `quote_validator_param` which passes numbers
+    /// and references by value
+    pub fn quote_validator_ref_param(&self) -> proc_macro2::TokenStream {
         let ident = &self.ident;
 
-        if self._type.starts_with("Option<") || is_list(&self._type) || is_map(&self._type) {
-            quote!(#ident)
-        } else if COW_TYPE.is_match(self._type.as_ref()) {
-            quote!(self.#ident.as_ref())
+        if self._type.starts_with("Option<") {
+            if self.is_optional_copied() {
+                quote!(&#ident)
+            } else {
+                quote!(#ident)
+            }
         } else {
-            quote!(self.#ident)
+            quote!(&self.#ident)
         }
     }
 
-    pub fn get_optional_validator_param(&self) -> proc_macro2::TokenStream {
-        let ident = &self.ident;
-        if self._type.starts_with("Option<&")
+    /// Whether the value of an optional field is copied out of the Option rather than borrowed
+    fn is_optional_copied(&self) -> bool {
+        self._type.starts_with("Option<&")
             || self._type.starts_with("Option<Option<&")
             || NUMBER_TYPES.contains(&self._type.as_ref())
-        {
+    }
+
+    pub fn get_optional_validator_param(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        if self.is_optional_copied() {
             quote!(#ident)
         } else {
             quote!(ref #ident)
@@ -86,66 +104,6 @@ impl FieldQuoter {
 
         tokens
     }
-
-    /// Wrap the quoted output of a validation with a for loop if
-    /// the field type is a vector
-    pub fn wrap_if_collection(&self, tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
-        let field_ident = &self.ident;
-        let field_name = &self.name;
-
-        // When we're using an option, we'll have the field unwrapped, so we should not access it
-        // through `self`.
-        let prefix = (!self._type.starts_with("Option<")).then(|| quote! { self. });
-
-        // When iterating over a list, the iterator has Item=T, while a map yields Item=(K, V), and
-        // we're only interested in V.
-        let args = if is_list(&self._type) {
-            quote! { #field_ident }
-        } else if is_map(&self._type) {
-            quote! { (_, #field_ident) }
-        } else {
-            return tokens;
-        };
-
-        quote! {
-            if !::validator::ValidationErrors::has_error(&result, #field_name) {
-                let results: Vec<_> = #prefix #field_ident.iter().map(|#args| {
-                    let mut result = ::std::result::Result::Ok(());
-                    #tokens
-                    result
-                }).collect();
-                result = ::validator::ValidationErrors::merge_all(result, #field_name, results);
-            }
-        }
-    }
-}
-
-fn is_map(_type: &str) -> bool {
-    if let Some(stripped) = _type.strip_prefix("Option<") {
-        is_map(stripped)
-    } else if let Some(stripped) = _type.strip_prefix("&") {
-        is_map(stripped)
-    } else {
-        _type.starts_with("HashMap<")
-            || _type.starts_with("FxHashMap<")
-            || _type.starts_with("FnvHashMap<")
-            || _type.starts_with("BTreeMap<")
-            || _type.starts_with("IndexMap<")
-    }
-}
-
-fn is_list(_type: &str) -> bool {
-    if let Some(stripped) = _type.strip_prefix("&") {
-        is_list(stripped)
-    } else if let Some(stripped) = _type.strip_prefix("Option<") {
-        is_list(stripped)
-    } else {
-        _type.starts_with("Vec<")
-            || _type.starts_with("HashSet<")
-            || _type.starts_with("BTreeSet<")
-            || _type.starts_with("IndexSet<")
-            || _type.starts_with("[")
-    }
 }
 
 /// Quote an actual end-user error creation automatically
@@ -157,12 +115,24 @@ fn quote_error(validation: &FieldValidation) -> proc_macro2::TokenStream {
         quote!()
     };
 
+    let set_severity_quoted = quote_warning_severity(validation.warning);
+
     quote!(
         let mut err = ::validator::ValidationError::new(#code);
         #add_message_quoted
+        #set_severity_quoted
     )
 }
 
+/// Sets the `Warning` severity on `err` for validators with `severity = "warning"`
+fn quote_warning_severity(warning: bool) -> proc_macro2::TokenStream {
+    if warning {
+        quote!(err.severity = ::validator::Severity::Warning;)
+    } else {
+        quote!()
+    }
+}
+
 pub fn quote_length_validation(
     field_quoter: &FieldQuoter,
     validation: &FieldValidation,
@@ -170,7 +140,7 @@ pub fn quote_length_validation(
     let field_name = &field_quoter.name;
     let validator_param = field_quoter.quote_validator_param();
 
-    if let Validator::Length { min, max, equal } = &validation.validator {
+    if let Validator::Length { min, max, equal, mode } = &validation.validator {
         let min_err_param_quoted = if let Some(v) = min {
             let v = value_or_path_to_tokens(v);
             quote!(err.add_param(::std::borrow::Cow::from("min"), &#v);)
@@ -200,18 +170,39 @@ pub fn quote_length_validation(
             &equal.clone().as_ref().map(value_or_path_to_tokens).map(|x| quote!(#x as u64)),
         );
 
+        let (validate_call, mode_err_param_quoted) = if let Some(mode) = mode {
+            let mode_ident = syn::Ident::new(&format!("{:?}", mode), Span::call_site());
+            let mode_name = mode.name();
+            (
+                quote!(::validator::validate_length_with_mode(
+                    #validator_param,
+                    #min_tokens,
+                    #max_tokens,
+                    #equal_tokens,
+                    ::validator::LengthMode::#mode_ident
+                )),
+                quote!(err.add_param(::std::borrow::Cow::from("mode"), &#mode_name);),
+            )
+        } else {
+            (
+                quote!(::validator::validate_length(
+                    #validator_param,
+                    #min_tokens,
+                    #max_tokens,
+                    #equal_tokens
+                )),
+                quote!(),
+            )
+        };
+
         let quoted_error = quote_error(validation);
         let quoted = quote!(
-            if !::validator::validate_length(
-                #validator_param,
-                #min_tokens,
-                #max_tokens,
-                #equal_tokens
-            ) {
+            if !#validate_call {
                 #quoted_error
                 #min_err_param_quoted
                 #max_err_param_quoted
                 #equal_err_param_quoted
+                #mode_err_param_quoted
                 err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
                 errors.add(#field_name, err);
             }
@@ -253,10 +244,17 @@ pub fn quote_range_validation(
             max.clone().map(|x| value_or_path_to_tokens(&x)).map(|x| quote!(#x as f64));
         let max_tokens = option_to_tokens(&max_tokens);
 
+        // A type parameter can't be cast, it is bound to be `Into<f64> + Copy` instead
+        let value = if field_quoter.type_param {
+            quote!(::std::convert::Into::<f64>::into(*#quoted_ident))
+        } else {
+            quote!(#quoted_ident as f64)
+        };
+
         let quoted_error = quote_error(validation);
         let quoted = quote!(
             if !::validator::validate_range(
-                #quoted_ident as f64,
+                #value,
                 #min_tokens,
                 #max_tokens
             ) {
@@ -353,6 +351,54 @@ pub fn quote_url_validation(
     field_quoter.wrap_if_option(quoted)
 }
 
+pub fn quote_hostname_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let validator_param = field_quoter.quote_validator_param();
+
+    let quoted_error = quote_error(validation);
+    let quoted = quote!(
+        if !::validator::validate_hostname(#validator_param) {
+            #quoted_error
+            err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+            errors.add(#field_name, err);
+        }
+    );
+
+    field_quoter.wrap_if_option(quoted)
+}
+
+pub fn quote_domain_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let validator_param = field_quoter.quote_validator_param();
+
+    if let Validator::Domain { wildcard, public_suffix } = validation.validator {
+        let validate_fn = if public_suffix {
+            quote!(::validator::validate_public_domain)
+        } else {
+            quote!(::validator::validate_domain)
+        };
+
+        let quoted_error = quote_error(validation);
+        let quoted = quote!(
+            if !#validate_fn(#validator_param, #wildcard) {
+                #quoted_error
+                err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                errors.add(#field_name, err);
+            }
+        );
+
+        return field_quoter.wrap_if_option(quoted);
+    }
+
+    unreachable!()
+}
+
 pub fn quote_email_validation(
     field_quoter: &FieldQuoter,
     validation: &FieldValidation,
@@ -372,6 +418,132 @@ pub fn quote_email_validation(
     field_quoter.wrap_if_option(quoted)
 }
 
+pub fn quote_char_class_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let validator_param = field_quoter.quote_validator_param();
+
+    if let Validator::CharClass { class, ascii_only } = validation.validator {
+        let class_ident = syn::Ident::new(&format!("{:?}", class), Span::call_site());
+        let quoted_error = quote_error(validation);
+        let quoted = quote!(
+            if let ::std::option::Option::Some((index, c)) = ::validator::first_invalid_char(
+                #validator_param,
+                ::validator::CharClass::#class_ident,
+                #ascii_only
+            ) {
+                #quoted_error
+                err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                err.add_param(::std::borrow::Cow::from("char"), &c);
+                err.add_param(::std::borrow::Cow::from("index"), &index);
+                errors.add(#field_name, err);
+            }
+        );
+
+        return field_quoter.wrap_if_option(quoted);
+    }
+
+    unreachable!()
+}
+
+pub fn quote_unique_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let validator_param = field_quoter.quote_validator_param();
+
+    if let Validator::Unique { ref by } = validation.validator {
+        let items = match by {
+            Some(by) => {
+                let by: syn::Path = syn::parse_str(by).unwrap();
+                quote!((#validator_param).iter().map(#by))
+            }
+            None => quote!((#validator_param).iter()),
+        };
+        let quoted_error = quote_error(validation);
+        // Each duplicate is reported at its index, the first occurrence being valid. If the other
+        // validators of the field failed, the duplicates are added to their errors with the index
+        // in the params instead since field errors can't be merged with a list.
+        let quoted = quote!(
+            let duplicates = ::validator::duplicate_indices(#items);
+            if !duplicates.is_empty() {
+                let kind = errors
+                    .errors_mut()
+                    .entry(::std::borrow::Cow::from(#field_name))
+                    .or_insert_with(|| {
+                        ::validator::ValidationErrorsKind::List(::std::collections::BTreeMap::new())
+                    });
+                for (index, first) in duplicates {
+                    #quoted_error
+                    err.add_param(::std::borrow::Cow::from("duplicate_of"), &first);
+                    match *kind {
+                        ::validator::ValidationErrorsKind::List(ref mut list) => {
+                            list.entry(index)
+                                .or_insert_with(::std::default::Default::default)
+                                .add("__all__", err);
+                        }
+                        ::validator::ValidationErrorsKind::Field(ref mut field_errors) => {
+                            err.add_param(::std::borrow::Cow::from("index"), &index);
+                            field_errors.push(err);
+                        }
+                        ::validator::ValidationErrorsKind::Struct(ref mut struct_errors) => {
+                            err.add_param(::std::borrow::Cow::from("index"), &index);
+                            struct_errors.add("__all__", err);
+                        }
+                    }
+                }
+            }
+        );
+
+        return field_quoter.wrap_if_option(quoted);
+    }
+
+    unreachable!()
+}
+
+pub fn quote_one_of_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let validator_param = field_quoter.quote_validator_ref_param();
+
+    let (function, values) = match validation.validator {
+        Validator::OneOf(ref values) => (quote!(::validator::validate_one_of), values),
+        Validator::NoneOf(ref values) => (quote!(::validator::validate_none_of), values),
+        _ => unreachable!(),
+    };
+    // The literals can always be serialized, the values of a path have to implement `Serialize`
+    let (values, add_values_param) = match values {
+        ValueOrPath::Value(literals) => {
+            let values = quote!([#(#literals),*]);
+            (values.clone(), quote!(err.add_param(::std::borrow::Cow::from("values"), &#values);))
+        }
+        ValueOrPath::Path(path) => {
+            let path: syn::Path = syn::parse_str(path).unwrap();
+            let values = quote!(#path[..]);
+            (
+                values.clone(),
+                quote!(::validator::one_of_path_values_must_implement_serialize(&mut err, &#values);),
+            )
+        }
+    };
+    let quoted_error = quote_error(validation);
+    let quoted = quote!(
+        if !#function(#validator_param, &#values) {
+            #quoted_error
+            err.add_param(::std::borrow::Cow::from("value"), #validator_param);
+            #add_values_param
+            errors.add(#field_name, err);
+        }
+    );
+
+    field_quoter.wrap_if_option(quoted)
+}
+
 pub fn quote_must_match_validation(
     field_quoter: &FieldQuoter,
     validation: &FieldValidation,
@@ -404,9 +576,11 @@ pub fn quote_custom_validation(
     let field_name = &field_quoter.name;
     let validator_param = field_quoter.quote_validator_param();
 
-    if let Validator::Custom { function, argument, .. } = &validation.validator {
+    if let Validator::Custom { function, argument, with_parent } = &validation.validator {
         let fn_ident: syn::Path = syn::parse_str(function).unwrap();
 
+        let parent = if *with_parent { quote!(, self) } else { quote!() };
+
         let access = if_chain! {
             if let Some(argument) = &**argument;
             if let Some(access) = &argument.arg_access;
@@ -422,14 +596,27 @@ pub fn quote_custom_validation(
         } else {
             quote!()
         };
+        let set_severity_quoted = quote_warning_severity(validation.warning);
 
+        let code = &validation.code;
+
+        // The function can return any `CustomError`, every error gets the message and the value.
+        // An `Err` without any error still fails with the code of the validator.
         let quoted = quote!(
-            match #fn_ident(#validator_param #access) {
+            match #fn_ident(#validator_param #parent #access) {
                 ::std::result::Result::Ok(()) => (),
-                ::std::result::Result::Err(mut err) => {
-                    #add_message_quoted
-                    err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
-                    errors.add(#field_name, err);
+                ::std::result::Result::Err(err) => {
+                    let add_details = |mut err: ::validator::ValidationError| {
+                        #add_message_quoted
+                        #set_severity_quoted
+                        err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                        err
+                    };
+                    if ::validator::CustomError::is_empty(&err) {
+                        errors.add(#field_name, add_details(::validator::ValidationError::new(#code)));
+                    } else {
+                        ::validator::CustomError::add_to(err, &mut errors, #field_name, add_details);
+                    }
                 },
             };
         );
@@ -448,12 +635,13 @@ pub fn quote_contains_validation(
     let validator_param = field_quoter.quote_validator_param();
 
     if let Validator::Contains(ref needle) = validation.validator {
+        let needle = needle_to_tokens(needle);
         let quoted_error = quote_error(validation);
         let quoted = quote!(
-            if !::validator::validate_contains(#validator_param, &#needle) {
+            if !::validator::validate_contains(#validator_param, #needle) {
                 #quoted_error
                 err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
-                err.add_param(::std::borrow::Cow::from("needle"), &#needle);
+                err.add_param(::std::borrow::Cow::from("needle"), #needle);
                 errors.add(#field_name, err);
             }
         );
@@ -464,6 +652,37 @@ pub fn quote_contains_validation(
     unreachable!();
 }
 
+pub fn quote_affix_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let validator_param = field_quoter.quote_validator_param();
+
+    let (function, needles, any, case_insensitive) = match validation.validator {
+        Validator::StartsWith { ref needles, any, case_insensitive } => {
+            (quote!(::validator::validate_starts_with), needles, any, case_insensitive)
+        }
+        Validator::EndsWith { ref needles, any, case_insensitive } => {
+            (quote!(::validator::validate_ends_with), needles, any, case_insensitive)
+        }
+        _ => unreachable!(),
+    };
+    // A single needle is reported as is, `any(..)` as a list
+    let needle_param = if any { quote!(&[#(#needles),*]) } else { quote!(#(#needles)*) };
+    let quoted_error = quote_error(validation);
+    let quoted = quote!(
+        if !#function(#validator_param, &[#(#needles),*], #case_insensitive) {
+            #quoted_error
+            err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+            err.add_param(::std::borrow::Cow::from("needle"), #needle_param);
+            errors.add(#field_name, err);
+        }
+    );
+
+    field_quoter.wrap_if_option(quoted)
+}
+
 pub fn quote_regex_validation(
     field_quoter: &FieldQuoter,
     validation: &FieldValidation,
@@ -471,16 +690,56 @@ pub fn quote_regex_validation(
     let field_name = &field_quoter.name;
     let validator_param = field_quoter.quote_validator_param();
 
-    if let Validator::Regex(ref re) = validation.validator {
-        let re_ident: syn::Path = syn::parse_str(re).unwrap();
+    if let Validator::Regex { ref regex, negate, case_insensitive, set } = validation.validator {
         let quoted_error = quote_error(validation);
-        let quoted = quote!(
-            if !#re_ident.is_match(#validator_param) {
-                #quoted_error
-                err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
-                errors.add(#field_name, err);
+        let not = if negate { quote!() } else { quote!(!) };
+        let quoted = match (regex, set) {
+            (ValueOrPath::Path(path), None) => {
+                let re_ident: syn::Path = syn::parse_str(path).unwrap();
+                quote!(
+                    if #not #re_ident.is_match(#validator_param) {
+                        #quoted_error
+                        err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                        errors.add(#field_name, err);
+                    }
+                )
             }
-        );
+            (ValueOrPath::Path(path), Some(mode)) => {
+                let set_ident: syn::Path = syn::parse_str(path).unwrap();
+                let is_invalid = match mode {
+                    RegexSetMode::Any => quote!(matches.is_empty()),
+                    RegexSetMode::All => quote!(matches.len() != #set_ident.len()),
+                    RegexSetMode::None => quote!(!matches.is_empty()),
+                };
+                quote!(
+                    let matches: ::std::vec::Vec<usize> = #set_ident.matches(#validator_param).into_iter().collect();
+                    if #is_invalid {
+                        #quoted_error
+                        err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                        err.add_param(::std::borrow::Cow::from("matches"), &matches);
+                        errors.add(#field_name, err);
+                    }
+                )
+            }
+            // The pattern was already checked by the derive, it is only compiled once on first use
+            (ValueOrPath::Value(pattern), _) => {
+                let compiled_pattern =
+                    if case_insensitive { format!("(?i){}", pattern) } else { pattern.clone() };
+                quote!(
+                    {
+                        ::validator::lazy_static! {
+                            static ref RE: ::validator::Regex = ::validator::Regex::new(#compiled_pattern).unwrap();
+                        }
+                        if #not RE.is_match(#validator_param) {
+                            #quoted_error
+                            err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                            err.add_param(::std::borrow::Cow::from("pattern"), &#pattern);
+                            errors.add(#field_name, err);
+                        }
+                    }
+                )
+            }
+        };
 
         return field_quoter.wrap_if_option(quoted);
     }
@@ -488,11 +747,38 @@ pub fn quote_regex_validation(
     unreachable!();
 }
 
-pub fn quote_nested_validation(field_quoter: &FieldQuoter) -> proc_macro2::TokenStream {
+pub fn quote_nested_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
     let field_name = &field_quoter.name;
-    let validator_field = field_quoter.quote_validator_field();
-    let quoted = quote!(result = ::validator::ValidationErrors::merge(result, #field_name, #validator_field.validate()););
-    field_quoter.wrap_if_option(field_quoter.wrap_if_collection(quoted))
+    let ident = &field_quoter.ident;
+
+    // The argument, if any, is a part of the parent's arguments. The wrappers and collections
+    // around the nested struct are handled by the `ValidateNested` implementations, the method
+    // call auto-derefs the field for the other smart pointers.
+    let args = match validation.validator.get_custom_argument() {
+        Some(CustomArgument { arg_access: Some(access), .. }) => quote!(#access),
+        _ => quote!(()),
+    };
+    let nested_result = match validation.validator {
+        Validator::Nested { max_depth: Some(max_depth), .. } => {
+            // The limit is counted for this field only, identified by its path
+            let struct_name = field_quoter.struct_ident.to_string();
+            quote!(self.#ident.validate_nested_with_max_depth(
+                #args,
+                ::std::concat!(::std::module_path!(), "::", #struct_name, ".", #field_name),
+                #max_depth,
+            ))
+        }
+        _ => quote!(self.#ident.validate_nested(#args)),
+    };
+    quote!(
+        result = ::validator::ValidationErrors::merge_nested(result, #field_name, {
+            use ::validator::ValidateNested;
+            #nested_result
+        });
+    )
 }
 
 pub fn quote_validator(
@@ -510,6 +796,12 @@ pub fn quote_validator(
         }
         Validator::Email => validations.push(quote_email_validation(field_quoter, validation)),
         Validator::Url => validations.push(quote_url_validation(field_quoter, validation)),
+        Validator::Hostname => {
+            validations.push(quote_hostname_validation(field_quoter, validation))
+        }
+        Validator::Domain { .. } => {
+            validations.push(quote_domain_validation(field_quoter, validation))
+        }
         Validator::MustMatch(_) => {
             validations.push(quote_must_match_validation(field_quoter, validation))
         }
@@ -519,24 +811,43 @@ pub fn quote_validator(
         Validator::Contains(_) => {
             validations.push(quote_contains_validation(field_quoter, validation))
         }
-        Validator::Regex(_) => validations.push(quote_regex_validation(field_quoter, validation)),
+        Validator::Regex { .. } => {
+            validations.push(quote_regex_validation(field_quoter, validation))
+        }
         #[cfg(feature = "card")]
         Validator::CreditCard => {
             validations.push(quote_credit_card_validation(field_quoter, validation))
         }
         #[cfg(feature = "phone")]
         Validator::Phone => validations.push(quote_phone_validation(field_quoter, validation)),
-        Validator::Nested => nested_validations.push(quote_nested_validation(field_quoter)),
+        Validator::Nested { .. } => {
+            nested_validations.push(quote_nested_validation(field_quoter, validation))
+        }
         #[cfg(feature = "unic")]
         Validator::NonControlCharacter => {
             validations.push(quote_non_control_character_validation(field_quoter, validation))
         }
-        Validator::Required | Validator::RequiredNested => {
+        Validator::Required { .. } | Validator::RequiredNested => {
             validations.push(quote_required_validation(field_quoter, validation))
         }
         Validator::DoesNotContain(_) => {
             validations.push(quote_does_not_contain_validation(field_quoter, validation))
         }
+        Validator::CharClass { .. } => {
+            validations.push(quote_char_class_validation(field_quoter, validation))
+        }
+        Validator::StartsWith { .. } | Validator::EndsWith { .. } => {
+            validations.push(quote_affix_validation(field_quoter, validation))
+        }
+        Validator::Unique { .. } => {
+            validations.push(quote_unique_validation(field_quoter, validation))
+        }
+        Validator::EmptyCheck(_) => {
+            validations.push(quote_empty_check_validation(field_quoter, validation))
+        }
+        Validator::OneOf(_) | Validator::NoneOf(_) => {
+            validations.push(quote_one_of_validation(field_quoter, validation))
+        }
     }
 }
 
@@ -556,13 +867,16 @@ pub fn quote_schema_validation(v: &SchemaValidation) -> proc_macro2::TokenStream
         quote!()
     };
 
-    let mut_err_token = if v.message.is_some() { quote!(mut) } else { quote!() };
+    let set_severity_quoted = quote_warning_severity(v.warning);
+
+    let mut_err_token = if v.message.is_some() || v.warning { quote!(mut) } else { quote!() };
 
     let quoted = quote!(
         match #fn_ident(#arg_quoted) {
             ::std::result::Result::Ok(()) => (),
             ::std::result::Result::Err(#mut_err_token err) => {
                 #add_message_quoted
+                #set_severity_quoted
                 errors.add("__all__", err);
             },
         };
@@ -581,6 +895,41 @@ pub fn quote_schema_validations(validation: &[SchemaValidation]) -> Vec<proc_mac
     validation.iter().map(quote_schema_validation).collect()
 }
 
+/// The condition for an empty or blank value
+fn quote_empty_check(
+    check: EmptyCheck,
+    param: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match check {
+        EmptyCheck::NonEmpty => quote!(::validator::validate_non_empty(#param)),
+        EmptyCheck::NotBlank => quote!(::validator::validate_not_blank(#param)),
+    }
+}
+
+pub fn quote_empty_check_validation(
+    field_quoter: &FieldQuoter,
+    validation: &FieldValidation,
+) -> proc_macro2::TokenStream {
+    let field_name = &field_quoter.name;
+    let validator_param = field_quoter.quote_validator_param();
+
+    if let Validator::EmptyCheck(check) = validation.validator {
+        let is_valid = quote_empty_check(check, &validator_param);
+        let quoted_error = quote_error(validation);
+        let quoted = quote!(
+            if !#is_valid {
+                #quoted_error
+                err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                errors.add(#field_name, err);
+            }
+        );
+
+        return field_quoter.wrap_if_option(quoted);
+    }
+
+    unreachable!()
+}
+
 pub fn quote_required_validation(
     field_quoter: &FieldQuoter,
     validation: &FieldValidation,
@@ -598,6 +947,24 @@ pub fn quote_required_validation(
         }
     );
 
+    // An empty value is reported the same way as a missing one
+    if let Validator::Required { empty_check: Some(check) } = validation.validator {
+        let is_valid = quote_empty_check(check, &field_quoter.quote_validator_param());
+        let quoted_empty = field_quoter.wrap_if_option(quote!(
+            if !#is_valid {
+                #quoted_error
+                err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
+                errors.add(#field_name, err);
+            }
+        ));
+        return quote!(
+            #quoted
+            else {
+                #quoted_empty
+            }
+        );
+    }
+
     quoted
 }
 
@@ -609,12 +976,13 @@ pub fn quote_does_not_contain_validation(
     let validator_param = field_quoter.quote_validator_param();
 
     if let Validator::DoesNotContain(ref needle) = validation.validator {
+        let needle = needle_to_tokens(needle);
         let quoted_error = quote_error(validation);
         let quoted = quote!(
-            if !::validator::validate_does_not_contain(#validator_param, &#needle) {
+            if !::validator::validate_does_not_contain(#validator_param, #needle) {
                 #quoted_error
                 err.add_param(::std::borrow::Cow::from("value"), &#validator_param);
-                err.add_param(::std::borrow::Cow::from("needle"), &#needle);
+                err.add_param(::std::borrow::Cow::from("needle"), #needle);
                 errors.add(#field_name, err);
             }
         );
@@ -0,0 +1,117 @@
+use proc_macro2::Span;
+use proc_macro_error::abort;
+use quote::quote;
+use syn::spanned::Spanned;
+
+/// The operations that can be used in `#[normalize(..)]`, applied in the order they are written
+const OPERATIONS: [&str; 5] = ["trim", "lowercase", "uppercase", "nfc", "collapse_whitespace"];
+
+/// What to do with a field
+enum FieldSanitization {
+    /// Run the string operations on all the strings of the field
+    Strings(Vec<syn::Ident>),
+    /// `#[normalize]`: call `Sanitize::sanitize` on the field
+    Nested,
+}
+
+pub fn impl_sanitize(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let fields = match ast.data {
+        syn::Data::Struct(syn::DataStruct { ref fields, .. }) => {
+            if fields.iter().any(|field| field.ident.is_none()) {
+                abort!(
+                    fields.span(),
+                    "struct has unnamed fields";
+                    help = "#[derive(Sanitize)] can only be used on structs with named fields";
+                );
+            }
+            fields.iter().cloned().collect::<Vec<_>>()
+        }
+        _ => abort!(ast.span(), "#[derive(Sanitize)] can only be used with structs"),
+    };
+
+    let mut sanitizations = vec![];
+    for field in &fields {
+        let ident = field.ident.as_ref().unwrap();
+        for sanitization in find_sanitizations_for_field(field) {
+            sanitizations.push(match sanitization {
+                FieldSanitization::Strings(operations) => {
+                    let functions = operations
+                        .iter()
+                        .map(|op| syn::Ident::new(&format!("sanitize_{}", op), op.span()));
+                    quote!(
+                        ::validator::StringsMut::for_each_string_mut(
+                            &mut self.#ident,
+                            &mut |s: &mut ::std::string::String| {
+                                #(::validator::#functions(s);)*
+                            },
+                        );
+                    )
+                }
+                FieldSanitization::Nested => {
+                    quote!(::validator::Sanitize::sanitize(&mut self.#ident);)
+                }
+            });
+        }
+    }
+
+    let ident = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::validator::Sanitize for #ident #ty_generics #where_clause {
+            fn sanitize(&mut self) {
+                #(#sanitizations)*
+            }
+        }
+    )
+}
+
+fn find_sanitizations_for_field(field: &syn::Field) -> Vec<FieldSanitization> {
+    let field_ident = field.ident.as_ref().unwrap().to_string();
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid attribute #[normalize] on field `{}`: {}", field_ident, msg);
+    };
+
+    let mut sanitizations = vec![];
+
+    for attr in &field.attrs {
+        if !attr.path.is_ident("normalize") {
+            continue;
+        }
+
+        match attr.parse_meta() {
+            Ok(syn::Meta::Path(_)) => sanitizations.push(FieldSanitization::Nested),
+            Ok(syn::Meta::List(syn::MetaList { ref nested, .. })) => {
+                if nested.is_empty() {
+                    error(attr.span(), "it needs at least one operation");
+                }
+                let operations = nested
+                    .iter()
+                    .map(|meta_item| match *meta_item {
+                        syn::NestedMeta::Meta(syn::Meta::Path(ref path)) => {
+                            match path.get_ident() {
+                                Some(op) if OPERATIONS.contains(&op.to_string().as_str()) => {
+                                    op.clone()
+                                }
+                                _ => error(
+                                    path.span(),
+                                    &format!(
+                                        "unknown operation `{}` (it only has `{}`)",
+                                        quote!(#path),
+                                        OPERATIONS.join("`, `")
+                                    ),
+                                ),
+                            }
+                        }
+                        _ => error(meta_item.span(), "operations can't take arguments"),
+                    })
+                    .collect();
+                sanitizations.push(FieldSanitization::Strings(operations));
+            }
+            Ok(syn::Meta::NameValue(_)) => error(attr.span(), "unexpected name=value argument"),
+            Err(e) => error(attr.span(), &format!("unable to parse the attribute: {}", e)),
+        }
+    }
+
+    sanitizations
+}
@@ -2,7 +2,9 @@ use proc_macro2::Span;
 use proc_macro_error::abort;
 use syn::spanned::Spanned;
 
-use validator_types::{CustomArgument, Validator};
+use validator_types::{
+    CharClass, CustomArgument, EmptyCheck, LengthMode, RegexSetMode, Validator, ValueOrPath,
+};
 
 use crate::{asserts::assert_custom_arg_type, lit::*};
 
@@ -11,8 +13,10 @@ pub struct SchemaValidation {
     pub function: String,
     pub args: Option<CustomArgument>,
     pub skip_on_field_errors: bool,
+    #[allow(dead_code)]
     pub code: Option<String>,
     pub message: Option<String>,
+    pub warning: bool,
 }
 
 /// This struct holds the combined validation information for one filed
@@ -40,12 +44,19 @@ impl FieldInformation {
 pub struct FieldValidation {
     pub code: String,
     pub message: Option<String>,
+    /// Whether the error has the `Warning` severity
+    pub warning: bool,
     pub validator: Validator,
 }
 
 impl FieldValidation {
     pub fn new(validator: Validator) -> FieldValidation {
-        FieldValidation { code: validator.code().to_string(), validator, message: None }
+        FieldValidation {
+            code: validator.code().to_string(),
+            validator,
+            message: None,
+            warning: false,
+        }
     }
 }
 
@@ -57,8 +68,9 @@ pub fn extract_length_validation(
     let mut min = None;
     let mut max = None;
     let mut equal = None;
+    let mut mode = None;
 
-    let (message, code) = extract_message_and_code("length", &field, meta_items);
+    let (message, code, warning) = extract_error_options("length", &field, meta_items);
 
     let error = |span: Span, msg: &str| -> ! {
         abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
@@ -69,7 +81,7 @@ pub fn extract_length_validation(
             if let syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) = *item {
                 let ident = path.get_ident().unwrap();
                 match ident.to_string().as_ref() {
-                    "message" | "code" => continue,
+                    "message" | "code" | "severity" => continue,
                     "min" => {
                         min = match lit_to_u64_or_path(lit) {
                             Some(s) => Some(s),
@@ -88,8 +100,14 @@ pub fn extract_length_validation(
                             None => error(lit.span(), "invalid argument type for `equal` of `length` validator: only number literals or value paths are allowed"),
                         };
                     }
+                    "mode" => {
+                        mode = match lit_to_string(lit).as_deref().and_then(LengthMode::from_name) {
+                            Some(m) => Some(m),
+                            None => error(lit.span(), "invalid argument for `mode` of `length` validator: only \"bytes\", \"chars\", \"graphemes\" or \"utf16\" are allowed"),
+                        };
+                    }
                     v => error(path.span(), &format!(
-                        "unknown argument `{}` for validator `length` (it only has `min`, `max`, `equal`)",
+                        "unknown argument `{}` for validator `length` (it only has `min`, `max`, `equal`, `mode`)",
                         v
                     ))
                 }
@@ -116,9 +134,10 @@ pub fn extract_length_validation(
         );
     }
 
-    let validator = Validator::Length { min, max, equal };
+    let validator = Validator::Length { min, max, equal, mode };
     FieldValidation {
         message,
+        warning,
         code: code.unwrap_or_else(|| validator.code().to_string()),
         validator,
     }
@@ -132,7 +151,7 @@ pub fn extract_range_validation(
     let mut min = None;
     let mut max = None;
 
-    let (message, code) = extract_message_and_code("range", &field, meta_items);
+    let (message, code, warning) = extract_error_options("range", &field, meta_items);
 
     let error = |span: Span, msg: &str| -> ! {
         abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
@@ -144,7 +163,7 @@ pub fn extract_range_validation(
                 syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
                     let ident = path.get_ident().unwrap();
                     match ident.to_string().as_ref() {
-                        "message" | "code" => continue,
+                        "message" | "code" | "severity" => continue,
                         "min" => {
                             min = match lit_to_f64_or_path(lit) {
                                 Some(s) => Some(s),
@@ -180,6 +199,241 @@ pub fn extract_range_validation(
     let validator = Validator::Range { min, max };
     FieldValidation {
         message,
+        warning,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+    }
+}
+
+pub fn extract_domain_validation(field: String, meta_items: &[syn::NestedMeta]) -> FieldValidation {
+    let mut wildcard = false;
+    let mut public_suffix = false;
+
+    let (message, code, warning) = extract_error_options("domain", &field, meta_items);
+
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
+    };
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(ref item) => match *item {
+                syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
+                    let ident = path.get_ident().unwrap();
+                    match ident.to_string().as_ref() {
+                        "message" | "code" | "severity" => continue,
+                        "wildcard" => {
+                            wildcard = match lit_to_bool(lit) {
+                                Some(b) => b,
+                                None => error(lit.span(), "invalid argument type for `wildcard` of `domain` validator: only a bool is allowed")
+                            };
+                        }
+                        "public_suffix" => {
+                            public_suffix = match lit_to_bool(lit) {
+                                Some(b) => b,
+                                None => error(lit.span(), "invalid argument type for `public_suffix` of `domain` validator: only a bool is allowed")
+                            };
+                            if public_suffix && !cfg!(feature = "public_suffix") {
+                                error(lit.span(), "`public_suffix` of `domain` validator requires the feature `public_suffix` to be enabled");
+                            }
+                        }
+                        v => error(path.span(), &format!(
+                            "unknown argument `{}` for validator `domain` (it only has `wildcard`, `public_suffix`)",
+                            v
+                        )),
+                    }
+                }
+                _ => abort!(
+                    item.span(),
+                    "unexpected item {:?} while parsing `domain` validator",
+                    item
+                ),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    let validator = Validator::Domain { wildcard, public_suffix };
+    FieldValidation {
+        message,
+        warning,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+    }
+}
+
+/// Extract a regex validation with either a `path` to a static Regex/RegexSet or an inline `pattern`,
+/// which is compiled here so an invalid one is a compilation error
+pub fn extract_regex_validation(
+    field: String,
+    attr: &syn::Attribute,
+    meta_items: &[syn::NestedMeta],
+) -> FieldValidation {
+    let mut value = None;
+    let mut negate = false;
+    let mut case_insensitive = false;
+    let mut set = None;
+
+    let (message, code, warning) = extract_error_options("regex", &field, meta_items);
+
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
+    };
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(ref item) => match *item {
+                syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
+                    let ident = path.get_ident().unwrap();
+                    let name = ident.to_string();
+                    match name.as_ref() {
+                        "message" | "code" | "severity" => continue,
+                        "path" | "pattern" => {
+                            if value.is_some() {
+                                error(path.span(), "only one of `path` and `pattern` can be set in `regex` validator");
+                            }
+                            let s = match lit_to_string(lit) {
+                                Some(s) => s,
+                                None => error(lit.span(), &format!("invalid argument type for `{}` of `regex` validator: only a string is allowed", name)),
+                            };
+                            value = if name == "path" {
+                                Some((ValueOrPath::Path(s), path.span()))
+                            } else {
+                                if let Err(e) = regex::Regex::new(&s) {
+                                    error(lit.span(), &format!("invalid regex for `pattern` of `regex` validator:\n{}", e));
+                                }
+                                Some((ValueOrPath::Value(s), path.span()))
+                            };
+                        }
+                        "negate" => {
+                            negate = match lit_to_bool(lit) {
+                                Some(b) => b,
+                                None => error(lit.span(), "invalid argument type for `negate` of `regex` validator: only a bool is allowed"),
+                            };
+                        }
+                        "case_insensitive" => {
+                            case_insensitive = match lit_to_bool(lit) {
+                                Some(b) => b,
+                                None => error(lit.span(), "invalid argument type for `case_insensitive` of `regex` validator: only a bool is allowed"),
+                            };
+                        }
+                        "set" => {
+                            set = match lit_to_string(lit).as_deref().and_then(RegexSetMode::from_name) {
+                                Some(m) => Some(m),
+                                None => error(lit.span(), "invalid argument for `set` of `regex` validator: only \"any\", \"all\" or \"none\" are allowed"),
+                            };
+                        }
+                        v => error(path.span(), &format!(
+                            "unknown argument `{}` for validator `regex` (it only has `path`, `pattern`, `negate`, `case_insensitive`, `set`)",
+                            v
+                        )),
+                    }
+                }
+                // flags can be used without a value: `negate` is `negate = true`
+                syn::Meta::Path(ref path) => match path.get_ident().map(|i| i.to_string()).as_deref() {
+                    Some("negate") => negate = true,
+                    Some("case_insensitive") => case_insensitive = true,
+                    _ => error(path.span(), "unknown flag for validator `regex` (it only has `negate`, `case_insensitive`)"),
+                },
+                _ => abort!(
+                    item.span(),
+                    "unexpected item {:?} while parsing `regex` validator",
+                    item
+                ),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    let (regex, span) = match value {
+        Some(v) => v,
+        None => error(
+            attr.span(),
+            "Validator `regex` requires either the `path` or the `pattern` argument",
+        ),
+    };
+
+    match regex {
+        ValueOrPath::Path(_) if case_insensitive => error(
+            span,
+            "`case_insensitive` can only be used with a `pattern` in `regex` validator, set the flag on the Regex itself instead",
+        ),
+        ValueOrPath::Value(_) if set.is_some() => {
+            error(span, "`set` can only be used with the `path` of a RegexSet in `regex` validator")
+        }
+        _ => (),
+    }
+    if negate && set.is_some() {
+        error(
+            span,
+            "`negate` can't be used with `set` in `regex` validator, use `set = \"none\"` instead",
+        );
+    }
+
+    let validator = Validator::Regex { regex, negate, case_insensitive, set };
+    FieldValidation {
+        message,
+        warning,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+    }
+}
+
+/// Extract ascii/alphanumeric/alpha/numeric/lowercase/uppercase/printable field validation
+pub fn extract_char_class_validation(
+    class: CharClass,
+    field: String,
+    meta_items: &[syn::NestedMeta],
+) -> FieldValidation {
+    let validator_name = class.name();
+    let mut ascii_only = false;
+
+    let (message, code, warning) = extract_error_options(validator_name, &field, meta_items);
+
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
+    };
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(ref item) => match *item {
+                syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
+                    let ident = path.get_ident().unwrap();
+                    match ident.to_string().as_ref() {
+                        "message" | "code" | "severity" => continue,
+                        "ascii_only" => {
+                            ascii_only = match lit_to_bool(lit) {
+                                Some(b) => b,
+                                None => error(lit.span(), &format!(
+                                    "invalid argument type for `ascii_only` of `{}` validator: only a bool is allowed",
+                                    validator_name
+                                )),
+                            };
+                        }
+                        v => error(
+                            path.span(),
+                            &format!(
+                            "unknown argument `{}` for validator `{}` (it only has `ascii_only`)",
+                            v, validator_name
+                        ),
+                        ),
+                    }
+                }
+                _ => abort!(
+                    item.span(),
+                    "unexpected item {:?} while parsing `{}` validator",
+                    item,
+                    validator_name
+                ),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    let validator = Validator::CharClass { class, ascii_only };
+    FieldValidation {
+        message,
+        warning,
         code: code.unwrap_or_else(|| validator.code().to_string()),
         validator,
     }
@@ -192,8 +446,9 @@ pub fn extract_custom_validation(
 ) -> FieldValidation {
     let mut function = None;
     let mut argument = None;
+    let mut with_parent = false;
 
-    let (message, code) = extract_message_and_code("custom", &field, meta_items);
+    let (message, code, warning) = extract_error_options("custom", &field, meta_items);
 
     let error = |span: Span, msg: &str| -> ! {
         abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
@@ -205,7 +460,7 @@ pub fn extract_custom_validation(
                 syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
                     let ident = path.get_ident().unwrap();
                     match ident.to_string().as_ref() {
-                        "message" | "code" => continue,
+                        "message" | "code" | "severity" => continue,
                         "function" => {
                             function = match lit_to_string(lit) {
                                 Some(s) => Some(s),
@@ -231,12 +486,19 @@ pub fn extract_custom_validation(
                                 None => error(lit.span(), "invalid argument type for `arg` of `custom` validator: expected a string")
                             };
                         }
+                        "with_parent" => {
+                            with_parent = match lit_to_bool(lit) {
+                                Some(b) => b,
+                                None => error(lit.span(), "invalid argument type for `with_parent` of `custom` validator: only a bool is allowed")
+                            };
+                        }
                         v => error(path.span(), &format!(
-                            "unknown argument `{}` for validator `custom` (it only has `function`, `arg`)",
+                            "unknown argument `{}` for validator `custom` (it only has `function`, `arg`, `with_parent`)",
                             v
                         )),
                     }
                 }
+                syn::Meta::Path(ref path) if path.is_ident("with_parent") => with_parent = true,
                 _ => abort!(
                     item.span(),
                     "unexpected item {:?} while parsing `custom` validator",
@@ -251,21 +513,96 @@ pub fn extract_custom_validation(
         error(attr.span(), "The validator `custom` requires the `function` parameter.");
     }
 
-    let validator = Validator::Custom { function: function.unwrap(), argument: Box::new(argument) };
+    let validator = Validator::Custom {
+        function: function.unwrap(),
+        argument: Box::new(argument),
+        with_parent,
+    };
     FieldValidation {
         message,
+        warning,
         code: code.unwrap_or_else(|| validator.code().to_string()),
         validator,
     }
 }
 
-/// Extract url/email/phone/non_control_character field validation with a code or a message
+/// Extract a nested validation with parentheses: the `arg` type given to the nested `validate_args`,
+/// `context` to give it the context of the struct and/or a `max_depth`
+pub fn extract_nested_validation(
+    field: String,
+    attr: &syn::Attribute,
+    meta_items: &[syn::NestedMeta],
+) -> FieldValidation {
+    let mut argument = None;
+    let mut context = false;
+    let mut max_depth = None;
+
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
+    };
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                ref path,
+                ref lit,
+                ..
+            })) => {
+                let ident = path.get_ident().unwrap();
+                match ident.to_string().as_ref() {
+                    "arg" => {
+                        match lit_to_string(lit) {
+                            Some(s) => {
+                                match syn::parse_str::<syn::Type>(s.as_str()) {
+                                    Ok(arg_type) => {
+                                        assert_custom_arg_type(&lit.span(), &arg_type);
+                                        argument = Some(CustomArgument::new(lit.span(), arg_type));
+                                    }
+                                    Err(_) => error(lit.span(), "invalid argument type for `arg` of `nested` validator: The string has to be a single type."),
+                                }
+                            }
+                            None => error(lit.span(), "invalid argument type for `arg` of `nested` validator: expected a string")
+                        };
+                    }
+                    "max_depth" => match lit_to_int(lit) {
+                        Some(depth) if depth > 0 => max_depth = Some(depth as usize),
+                        _ => error(lit.span(), "invalid argument for `max_depth` of `nested` validator: expected a positive integer"),
+                    },
+                    v => error(path.span(), &format!(
+                        "unknown argument `{}` for validator `nested` (it only has `arg`, `context`, `max_depth`)",
+                        v
+                    )),
+                }
+            }
+            syn::NestedMeta::Meta(syn::Meta::Path(ref path)) if path.is_ident("context") => {
+                context = true
+            }
+            _ => error(meta_item.span(), "unexpected item while parsing `nested` validator"),
+        }
+    }
+
+    match (&argument, context) {
+        (None, false) if max_depth.is_none() => error(
+            attr.span(),
+            "The validator `nested` with parentheses requires the `arg`, `context` or `max_depth` parameter.",
+        ),
+        (Some(_), true) => error(
+            attr.span(),
+            "The validator `nested` can't have both `arg` and `context`: the context is the argument",
+        ),
+        _ => (),
+    }
+
+    FieldValidation::new(Validator::Nested { argument: Box::new(argument), context, max_depth })
+}
+
+/// Extract url/email/hostname/phone/non_control_character field validation with a code or a message
 pub fn extract_argless_validation(
     validator_name: String,
     field: String,
     meta_items: &[syn::NestedMeta],
 ) -> FieldValidation {
-    let (message, code) = extract_message_and_code(&validator_name, &field, meta_items);
+    let (message, code, warning) = extract_error_options(&validator_name, &field, meta_items);
 
     for meta_item in meta_items {
         match *meta_item {
@@ -273,7 +610,7 @@ pub fn extract_argless_validation(
                 syn::Meta::NameValue(syn::MetaNameValue { ref path, .. }) => {
                     let ident = path.get_ident().unwrap();
                     match ident.to_string().as_ref() {
-                        "message" | "code" => continue,
+                        "message" | "code" | "severity" => continue,
                         v => abort!(
                             meta_item.span(),
                             "Unknown argument `{}` for validator `{}` on field `{}`",
@@ -295,24 +632,427 @@ pub fn extract_argless_validation(
 
     let validator = match validator_name.as_ref() {
         "email" => Validator::Email,
+        "hostname" => Validator::Hostname,
         #[cfg(feature = "card")]
         "credit_card" => Validator::CreditCard,
         #[cfg(feature = "phone")]
         "phone" => Validator::Phone,
         #[cfg(feature = "unic")]
         "non_control_character" => Validator::NonControlCharacter,
-        "required" => Validator::Required,
+        "non_empty" => Validator::EmptyCheck(EmptyCheck::NonEmpty),
+        "not_blank" => Validator::EmptyCheck(EmptyCheck::NotBlank),
         _ => Validator::Url,
     };
 
     FieldValidation {
         message,
+        warning,
         code: code.unwrap_or_else(|| validator.code().to_string()),
         validator,
     }
 }
 
-/// For custom, contains, regex, must_match
+/// Extract a contains/does_not_contain validation: the needle is either a literal `pattern`
+/// of any type or the `path` to a const/static
+pub fn extract_contains_validation(
+    validator_name: &str,
+    field: String,
+    attr: &syn::Attribute,
+    meta_items: &[syn::NestedMeta],
+) -> FieldValidation {
+    let mut needle = None;
+
+    let (message, code, warning) = extract_error_options(validator_name, &field, meta_items);
+
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
+    };
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(ref item) => match *item {
+                syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
+                    let ident = path.get_ident().unwrap();
+                    let name = ident.to_string();
+                    match name.as_ref() {
+                        "message" | "code" | "severity" => continue,
+                        "pattern" | "path" => {
+                            if needle.is_some() {
+                                error(path.span(), &format!(
+                                    "only one of `pattern` and `path` can be set in `{}` validator",
+                                    validator_name
+                                ));
+                            }
+                            needle = if name == "pattern" {
+                                Some(ValueOrPath::Value(lit.clone()))
+                            } else {
+                                match lit_to_string(lit) {
+                                    Some(s) => Some(ValueOrPath::Path(s)),
+                                    None => error(lit.span(), &format!(
+                                        "invalid argument type for `path` of `{}` validator: only a string is allowed",
+                                        validator_name
+                                    )),
+                                }
+                            };
+                        }
+                        v => error(path.span(), &format!(
+                            "unknown argument `{}` for validator `{}` (it only has `pattern`, `path`)",
+                            v, validator_name
+                        )),
+                    }
+                }
+                _ => abort!(
+                    item.span(),
+                    "unexpected item {:?} while parsing `{}` validator",
+                    item,
+                    validator_name
+                ),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    let needle = match needle {
+        Some(n) => n,
+        None => error(
+            attr.span(),
+            &format!(
+                "Validator `{}` requires either the `pattern` or the `path` argument",
+                validator_name
+            ),
+        ),
+    };
+
+    let validator = match validator_name {
+        "contains" => Validator::Contains(needle),
+        "does_not_contain" => Validator::DoesNotContain(needle),
+        _ => unreachable!(),
+    };
+    FieldValidation {
+        message,
+        warning,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+    }
+}
+
+/// Extract a starts_with/ends_with validation: either a single `pattern` or `any(..)` of several
+pub fn extract_affix_validation(
+    validator_name: &str,
+    field: String,
+    attr: &syn::Attribute,
+    meta_items: &[syn::NestedMeta],
+) -> FieldValidation {
+    let mut needles: Option<Vec<String>> = None;
+    let mut any = false;
+    let mut case_insensitive = false;
+
+    let (message, code, warning) = extract_error_options(validator_name, &field, meta_items);
+
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
+    };
+    let only_once = |span: Span| -> ! {
+        error(
+            span,
+            &format!(
+                "only one of `pattern` and `any` can be set in `{}` validator",
+                validator_name
+            ),
+        );
+    };
+    let to_string = |lit: &syn::Lit| -> String {
+        match lit_to_string(lit) {
+            Some(s) => s,
+            None => error(
+                lit.span(),
+                &format!(
+                    "invalid argument type for `{}` validator: only strings are allowed",
+                    validator_name
+                ),
+            ),
+        }
+    };
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(ref item) => match *item {
+                syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
+                    let ident = path.get_ident().unwrap();
+                    match ident.to_string().as_ref() {
+                        "message" | "code" | "severity" => continue,
+                        "pattern" => {
+                            if needles.is_some() {
+                                only_once(path.span());
+                            }
+                            needles = Some(vec![to_string(lit)]);
+                        }
+                        "case_insensitive" => {
+                            case_insensitive = match lit_to_bool(lit) {
+                                Some(b) => b,
+                                None => error(lit.span(), &format!(
+                                    "invalid argument type for `case_insensitive` of `{}` validator: only a bool is allowed",
+                                    validator_name
+                                )),
+                            };
+                        }
+                        v => error(path.span(), &format!(
+                            "unknown argument `{}` for validator `{}` (it only has `pattern`, `any`, `case_insensitive`)",
+                            v, validator_name
+                        )),
+                    }
+                }
+                syn::Meta::List(syn::MetaList { ref path, ref nested, .. })
+                    if path.is_ident("any") =>
+                {
+                    if needles.is_some() {
+                        only_once(path.span());
+                    }
+                    let values = nested
+                        .iter()
+                        .map(|n| match *n {
+                            syn::NestedMeta::Lit(ref lit) => to_string(lit),
+                            _ => error(n.span(), "`any` only takes string literals"),
+                        })
+                        .collect::<Vec<_>>();
+                    if values.is_empty() {
+                        error(path.span(), "`any` requires at least one value");
+                    }
+                    any = true;
+                    needles = Some(values);
+                }
+                syn::Meta::Path(ref path) if path.is_ident("case_insensitive") => {
+                    case_insensitive = true;
+                }
+                _ => abort!(
+                    item.span(),
+                    "unexpected item {:?} while parsing `{}` validator",
+                    item,
+                    validator_name
+                ),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    let needles = match needles {
+        Some(n) => n,
+        None => error(
+            attr.span(),
+            &format!(
+                "Validator `{}` requires either the `pattern` or the `any` argument",
+                validator_name
+            ),
+        ),
+    };
+
+    let validator = match validator_name {
+        "starts_with" => Validator::StartsWith { needles, any, case_insensitive },
+        "ends_with" => Validator::EndsWith { needles, any, case_insensitive },
+        _ => unreachable!(),
+    };
+    FieldValidation {
+        message,
+        warning,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+    }
+}
+
+/// Extract a required validation, which can also treat empty (`non_empty`) or blank
+/// (`not_blank`) values as missing
+pub fn extract_required_validation(
+    field: String,
+    meta_items: &[syn::NestedMeta],
+) -> FieldValidation {
+    let mut empty_check = None;
+
+    let (message, code, warning) = extract_error_options("required", &field, meta_items);
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(ref item) => match *item {
+                syn::Meta::NameValue(syn::MetaNameValue { ref path, .. }) => {
+                    let ident = path.get_ident().unwrap();
+                    match ident.to_string().as_ref() {
+                        "message" | "code" | "severity" => continue,
+                        v => abort!(
+                            path.span(),
+                            "Unknown argument `{}` for validator `required` on field `{}`",
+                            v,
+                            field
+                        ),
+                    }
+                }
+                syn::Meta::Path(ref path) => {
+                    let name = path.get_ident().map(|i| i.to_string()).unwrap_or_default();
+                    match EmptyCheck::from_name(&name) {
+                        Some(_) if empty_check.is_some() => abort!(
+                            path.span(),
+                            "Only one of `non_empty` and `not_blank` can be used for validator `required` on field `{}`",
+                            field
+                        ),
+                        Some(check) => empty_check = Some(check),
+                        None => abort!(
+                            path.span(),
+                            "Unknown flag for validator `required` on field `{}` (it only has `non_empty`, `not_blank`)",
+                            field
+                        ),
+                    }
+                }
+                _ => abort!(
+                    meta_item.span(),
+                    "unexpected item {:?} while parsing `required` validator",
+                    item
+                ),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    let validator = Validator::Required { empty_check };
+    FieldValidation {
+        message,
+        warning,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+    }
+}
+
+/// Extract a unique validation with an optional `by` key function
+pub fn extract_unique_validation(field: String, meta_items: &[syn::NestedMeta]) -> FieldValidation {
+    let mut by = None;
+
+    let (message, code, warning) = extract_error_options("unique", &field, meta_items);
+
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
+    };
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Meta(ref item) => match *item {
+                syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
+                    let ident = path.get_ident().unwrap();
+                    match ident.to_string().as_ref() {
+                        "message" | "code" | "severity" => continue,
+                        "by" => {
+                            by = match lit_to_string(lit) {
+                                Some(s) => Some(s),
+                                None => error(lit.span(), "invalid argument type for `by` of `unique` validator: only a string is allowed"),
+                            };
+                        }
+                        v => error(
+                            path.span(),
+                            &format!(
+                                "unknown argument `{}` for validator `unique` (it only has `by`)",
+                                v
+                            ),
+                        ),
+                    }
+                }
+                _ => abort!(
+                    item.span(),
+                    "unexpected item {:?} while parsing `unique` validator",
+                    item
+                ),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    let validator = Validator::Unique { by };
+    FieldValidation {
+        message,
+        warning,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+    }
+}
+
+/// Extract a one_of/none_of validation: either a list of literals or the `path` to a const
+/// slice/array of values
+pub fn extract_one_of_validation(
+    validator_name: &str,
+    field: String,
+    attr: &syn::Attribute,
+    meta_items: &[syn::NestedMeta],
+) -> FieldValidation {
+    let mut literals = vec![];
+    let mut path = None;
+
+    let (message, code, warning) = extract_error_options(validator_name, &field, meta_items);
+
+    let error = |span: Span, msg: &str| -> ! {
+        abort!(span, "Invalid attribute #[validate] on field `{}`: {}", field, msg);
+    };
+
+    for meta_item in meta_items {
+        match *meta_item {
+            syn::NestedMeta::Lit(ref lit) => literals.push(lit.clone()),
+            syn::NestedMeta::Meta(ref item) => match *item {
+                syn::Meta::NameValue(syn::MetaNameValue { path: ref p, ref lit, .. }) => {
+                    let ident = p.get_ident().unwrap();
+                    match ident.to_string().as_ref() {
+                        "message" | "code" | "severity" => continue,
+                        "path" => {
+                            path = match lit_to_string(lit) {
+                                Some(s) => Some(s),
+                                None => error(lit.span(), &format!(
+                                    "invalid argument type for `path` of `{}` validator: only a string is allowed",
+                                    validator_name
+                                )),
+                            };
+                        }
+                        v => error(
+                            p.span(),
+                            &format!(
+                                "unknown argument `{}` for validator `{}` (it only has `path`)",
+                                v, validator_name
+                            ),
+                        ),
+                    }
+                }
+                _ => abort!(
+                    item.span(),
+                    "unexpected item {:?} while parsing `{}` validator",
+                    item,
+                    validator_name
+                ),
+            },
+        }
+    }
+
+    let values = match (path, literals.is_empty()) {
+        (Some(p), true) => ValueOrPath::Path(p),
+        (None, false) => ValueOrPath::Value(literals),
+        (Some(_), false) => error(
+            attr.span(),
+            &format!(
+                "Validator `{}` takes either a list of values or a `path`, not both",
+                validator_name
+            ),
+        ),
+        (None, true) => error(
+            attr.span(),
+            &format!("Validator `{}` requires either a list of values or a `path`", validator_name),
+        ),
+    };
+
+    let validator = match validator_name {
+        "one_of" => Validator::OneOf(values),
+        "none_of" => Validator::NoneOf(values),
+        _ => unreachable!(),
+    };
+    FieldValidation {
+        message,
+        warning,
+        code: code.unwrap_or_else(|| validator.code().to_string()),
+        validator,
+    }
+}
+
+/// For custom, must_match
 pub fn extract_one_arg_validation(
     val_name: &str,
     validator_name: String,
@@ -320,7 +1060,7 @@ pub fn extract_one_arg_validation(
     meta_items: &[syn::NestedMeta],
 ) -> FieldValidation {
     let mut value = None;
-    let (message, code) = extract_message_and_code(&validator_name, &field, meta_items);
+    let (message, code, warning) = extract_error_options(&validator_name, &field, meta_items);
 
     for meta_item in meta_items {
         match *meta_item {
@@ -328,7 +1068,7 @@ pub fn extract_one_arg_validation(
                 syn::Meta::NameValue(syn::MetaNameValue { ref path, ref lit, .. }) => {
                     let ident = path.get_ident().unwrap();
                     match ident.to_string().as_ref() {
-                        "message" | "code" => continue,
+                        "message" | "code" | "severity" => continue,
                         v if v == val_name => {
                             value = match lit_to_string(lit) {
                                 Some(s) => Some(s),
@@ -369,28 +1109,33 @@ pub fn extract_one_arg_validation(
     }
 
     let validator = match validator_name.as_ref() {
-        "custom" => Validator::Custom { function: value.unwrap(), argument: Box::new(None) },
-        "contains" => Validator::Contains(value.unwrap()),
-        "does_not_contain" => Validator::DoesNotContain(value.unwrap()),
+        "custom" => Validator::Custom {
+            function: value.unwrap(),
+            argument: Box::new(None),
+            with_parent: false,
+        },
         "must_match" => Validator::MustMatch(value.unwrap()),
-        "regex" => Validator::Regex(value.unwrap()),
         _ => unreachable!(),
     };
 
     FieldValidation {
         message,
+        warning,
         code: code.unwrap_or_else(|| validator.code().to_string()),
         validator,
     }
 }
 
-fn extract_message_and_code(
+/// Extracts the `message`, `code` and `severity` arguments every validator accepts, the
+/// severity being returned as whether it is a warning
+fn extract_error_options(
     validator_name: &str,
     field: &str,
     meta_items: &[syn::NestedMeta],
-) -> (Option<String>, Option<String>) {
+) -> (Option<String>, Option<String>, bool) {
     let mut message = None;
     let mut code = None;
+    let mut warning = false;
 
     for meta_item in meta_items {
         if let syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
@@ -421,10 +1166,21 @@ fn extract_message_and_code(
                                 ),
                     };
                 }
+                "severity" => {
+                    warning = match lit_to_string(lit).as_deref() {
+                        Some("warning") => true,
+                        Some("error") => false,
+                        _ => abort!(
+                                    meta_item.span(),
+                                    "Invalid argument for `severity` for validator `{}` on field `{}`: only \"error\" or \"warning\" are allowed",
+                                    validator_name, field
+                                ),
+                    };
+                }
                 _ => continue,
             }
         }
     }
 
-    (message, code)
+    (message, code, warning)
 }
@@ -0,0 +1,96 @@
+use validator::Validate;
+
+#[test]
+fn can_validate_starts_with_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(starts_with = "usr_")]
+        val: String,
+    }
+
+    let s = TestStruct { val: "usr_123".to_string() };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn value_not_starting_with_prefix_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(starts_with = "usr_")]
+        val: String,
+    }
+
+    let s = TestStruct { val: "org_123".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "starts_with");
+    assert_eq!(errs["val"][0].params["value"], "org_123");
+    assert_eq!(errs["val"][0].params["needle"], "usr_");
+}
+
+#[test]
+fn can_validate_any_prefix_or_suffix() {
+    #[derive(Debug, Validate)]
+    struct TestStruct<'a> {
+        #[validate(starts_with(any("usr_", "org_")))]
+        id: &'a str,
+        #[validate(ends_with(any(".png", ".jpg"), case_insensitive))]
+        file_name: Option<String>,
+    }
+
+    let s = TestStruct { id: "org_1", file_name: Some("cat.JPG".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { id: "grp_1", file_name: Some("cat.gif".to_string()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["id"][0].code, "starts_with");
+    assert_eq!(errs["id"][0].params["needle"], serde_json::json!(["usr_", "org_"]));
+    assert_eq!(errs["file_name"][0].code, "ends_with");
+    assert_eq!(errs["file_name"][0].params["value"], "cat.gif");
+    assert_eq!(errs["file_name"][0].params["needle"], serde_json::json!([".png", ".jpg"]));
+}
+
+#[test]
+fn can_validate_case_insensitive_suffix() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(ends_with(pattern = ".pdf", case_insensitive = true))]
+        val: String,
+    }
+
+    let s = TestStruct { val: "report.PDF".to_string() };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "report.PDF.exe".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].params["needle"], ".pdf");
+}
+
+#[test]
+fn can_specify_code_and_message_for_ends_with() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(ends_with(pattern = ".rs", code = "oops", message = "not a Rust file"))]
+        val: String,
+    }
+
+    let s = TestStruct { val: "main.c".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "oops");
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "not a Rust file");
+}
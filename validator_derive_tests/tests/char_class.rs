@@ -0,0 +1,110 @@
+use validator::Validate;
+
+#[test]
+fn can_validate_char_classes_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(ascii)]
+        ascii: String,
+        #[validate(alphanumeric)]
+        alphanumeric: String,
+        #[validate(alpha)]
+        alpha: String,
+        #[validate(numeric)]
+        numeric: String,
+        #[validate(lowercase)]
+        lowercase: String,
+        #[validate(uppercase)]
+        uppercase: String,
+        #[validate(printable)]
+        printable: String,
+    }
+
+    let s = TestStruct {
+        ascii: "abc-123".to_string(),
+        alphanumeric: "日本123".to_string(),
+        alpha: "héllo".to_string(),
+        numeric: "0123".to_string(),
+        lowercase: "usr_123".to_string(),
+        uppercase: "SKU-123".to_string(),
+        printable: "Hello, world!".to_string(),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn invalid_char_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(alphanumeric)]
+        val: String,
+    }
+
+    let s = TestStruct { val: "日本 1".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "alphanumeric");
+    assert_eq!(errs["val"][0].params["value"], "日本 1");
+    assert_eq!(errs["val"][0].params["char"], " ");
+    assert_eq!(errs["val"][0].params["index"], 2);
+}
+
+#[test]
+fn can_restrict_char_class_to_ascii() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(alpha(ascii_only = true))]
+        val: Option<String>,
+    }
+
+    let s = TestStruct { val: Some("hello".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: Some("héllo".to_string()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "alpha");
+    assert_eq!(errs["val"][0].params["char"], "é");
+    assert_eq!(errs["val"][0].params["index"], 1);
+}
+
+#[test]
+fn can_specify_code_for_char_class() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(lowercase(code = "oops"))]
+        val: String,
+    }
+    let s = TestStruct { val: "Bob".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "oops");
+}
+
+#[test]
+fn can_specify_message_for_char_class() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(numeric(ascii_only = true, message = "oops"))]
+        val: String,
+    }
+    let s = TestStruct { val: "12a".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
+}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(starts_with = "1")]
+    s: u32,
+}
+
+fn main() {}
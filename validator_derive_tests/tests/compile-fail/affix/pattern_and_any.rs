@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(ends_with(pattern = ".png", any(".jpg", ".gif")))]
+    s: String,
+}
+
+fn main() {}
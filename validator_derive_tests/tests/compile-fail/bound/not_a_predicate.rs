@@ -0,0 +1,10 @@
+use validator::Validate;
+
+#[derive(Validate)]
+#[validate(bound = "T Validate")]
+struct Test<T> {
+    #[validate]
+    item: T,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use validator::{Validate, ValidationError};
+
+struct Ctx;
+
+fn check(_: &str, _: i32) -> Result<(), ValidationError> {
+    Ok(())
+}
+
+#[derive(Validate)]
+#[validate(context = "Ctx")]
+struct Test {
+    #[validate(custom(function = "check", arg = "i32"))]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use validator::{Validate, ValidationError};
+
+fn check(_: &str, _: &Test) -> Result<(), ValidationError> {
+    Ok(())
+}
+
+#[derive(Validate)]
+struct Test {
+    #[validate(custom(function = "check", with_parent = "yes"))]
+    s: String,
+}
+
+fn main() {}
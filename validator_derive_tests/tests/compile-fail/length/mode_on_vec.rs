@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(length(max = 2, mode = "bytes"))]
+    s: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(length(max = 2, mode = "words"))]
+    s: String,
+}
+
+fn main() {}
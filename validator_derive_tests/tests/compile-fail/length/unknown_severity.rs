@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(length(min = 2, severity = "info"))]
+    s: String,
+}
+
+fn main() {}
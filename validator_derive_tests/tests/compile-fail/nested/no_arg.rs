@@ -0,0 +1,15 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Child {
+    #[validate(length(min = 1))]
+    s: String,
+}
+
+#[derive(Validate)]
+struct Test {
+    #[validate(nested())]
+    child: Child,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Node {
+    #[validate(nested(max_depth = 0))]
+    children: Vec<Node>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(one_of(code = "status"))]
+    s: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(none_of("admin", path = "crate::RESERVED"))]
+    s: String,
+}
+
+fn main() {}
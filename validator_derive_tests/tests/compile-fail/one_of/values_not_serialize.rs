@@ -0,0 +1,20 @@
+use validator::Validate;
+
+#[derive(PartialEq)]
+struct Status(u8);
+
+const STATUSES: &[Status] = &[Status(1), Status(2)];
+
+#[derive(Validate)]
+struct Test {
+    #[validate(one_of(path = "STATUSES"))]
+    status: u8,
+}
+
+impl PartialEq<Status> for u8 {
+    fn eq(&self, other: &Status) -> bool {
+        *self == other.0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(regex(pattern = "^[a-z+$"))]
+    s: String,
+}
+
+fn main() {}
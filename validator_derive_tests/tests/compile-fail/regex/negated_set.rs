@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(regex(path = "crate::RE", set = "none", negate))]
+    s: String,
+}
+
+fn main() {}
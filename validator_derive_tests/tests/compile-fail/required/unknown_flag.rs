@@ -0,0 +1,9 @@
+use validator::Validate;
+
+#[derive(Validate)]
+struct Test {
+    #[validate(required(not_empty))]
+    s: Option<String>,
+}
+
+fn main() {}
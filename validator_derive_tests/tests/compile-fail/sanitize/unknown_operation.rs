@@ -0,0 +1,9 @@
+use validator::Sanitize;
+
+#[derive(Sanitize)]
+struct Test {
+    #[normalize(trim, capitalize)]
+    s: String,
+}
+
+fn main() {}
@@ -66,3 +66,55 @@ fn can_specify_message_for_contains() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+const PRIMARY: &str = "primary";
+static ROOT_ID: u32 = 0;
+
+#[test]
+fn can_validate_contains_in_collections() {
+    use std::collections::{BTreeMap, HashSet};
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(contains = "primary")]
+        tags: Vec<String>,
+        #[validate(contains(path = "crate::PRIMARY"))]
+        labels: Option<Vec<String>>,
+        #[validate(contains = 0)]
+        ids: Vec<u32>,
+        #[validate(contains(path = "crate::ROOT_ID", message = "the root is required"))]
+        parents: HashSet<u32>,
+        #[validate(contains(pattern = 'a'))]
+        letters: BTreeMap<char, usize>,
+    }
+
+    let s = TestStruct {
+        tags: vec!["primary".to_string()],
+        labels: Some(vec!["urgent".to_string(), "primary".to_string()]),
+        ids: vec![2, 0],
+        parents: vec![0].into_iter().collect(),
+        letters: vec![('a', 1)].into_iter().collect(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        tags: vec!["prim".to_string()],
+        labels: Some(vec![]),
+        ids: vec![2],
+        parents: HashSet::new(),
+        letters: BTreeMap::new(),
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 5);
+    assert_eq!(errs["tags"][0].code, "contains");
+    assert_eq!(errs["tags"][0].params["needle"], "primary");
+    assert_eq!(errs["tags"][0].params["value"], serde_json::json!(["prim"]));
+    assert_eq!(errs["labels"][0].params["needle"], "primary");
+    assert_eq!(errs["ids"][0].params["needle"], 0);
+    assert_eq!(errs["parents"][0].params["needle"], 0);
+    assert_eq!(errs["parents"][0].clone().message.unwrap(), "the root is required");
+    assert_eq!(errs["letters"][0].params["needle"], "a");
+}
@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+use validator::{Validate, ValidateArgs, ValidateWith, ValidationError, ValidationErrorsKind};
+
+struct Registry {
+    taken_names: HashSet<&'static str>,
+    domain: &'static str,
+    max_members: usize,
+}
+
+fn registry() -> Registry {
+    Registry {
+        taken_names: ["admin", "root"].iter().copied().collect(),
+        domain: "@acme.com",
+        max_members: 2,
+    }
+}
+
+fn name_available(name: &str, registry: &Registry) -> Result<(), ValidationError> {
+    if registry.taken_names.contains(name) {
+        return Err(ValidationError::new("taken"));
+    }
+    Ok(())
+}
+
+fn in_domain(email: &str, registry: &Registry) -> Result<(), ValidationError> {
+    if !email.ends_with(registry.domain) {
+        return Err(ValidationError::new("domain"));
+    }
+    Ok(())
+}
+
+fn not_owner_email(email: &str, team: &Team, registry: &Registry) -> Result<(), ValidationError> {
+    if email == team.owner.email {
+        return Err(ValidationError::new("owner_email"));
+    }
+    in_domain(email, registry)
+}
+
+fn team_size(team: &Team, registry: &Registry) -> Result<(), ValidationError> {
+    if team.members.len() > registry.max_members {
+        return Err(ValidationError::new("too_many_members"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Validate)]
+#[validate(context = "Registry")]
+struct Member {
+    #[validate(custom = "in_domain")]
+    email: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(context = "Registry")]
+#[validate(schema(function = "team_size"))]
+struct Team {
+    #[validate(length(min = 2), custom = "name_available")]
+    name: String,
+    #[validate(nested(context))]
+    owner: Member,
+    #[validate(custom(function = "not_owner_email", with_parent))]
+    contact: Option<String>,
+    #[validate(nested(context))]
+    members: Vec<Member>,
+}
+
+fn member(email: &str) -> Member {
+    Member { email: email.to_string() }
+}
+
+fn valid_team() -> Team {
+    Team {
+        name: "rockets".to_string(),
+        owner: member("bob@acme.com"),
+        contact: Some("team@acme.com".to_string()),
+        members: vec![member("alice@acme.com")],
+    }
+}
+
+#[test]
+fn can_validate_with_a_context() {
+    let registry = registry();
+    let team = valid_team();
+    assert!(team.validate_with(&registry).is_ok());
+    assert!(team.validate_args(&registry).is_ok());
+    assert!(team.validate_with_context_and_warnings(&registry).unwrap().is_empty());
+}
+
+#[test]
+fn every_function_is_given_the_context() {
+    let registry = registry();
+    let mut team = valid_team();
+    team.name = "admin".to_string();
+    team.contact = Some("bob@acme.com".to_string());
+    team.members.push(member("carol@example.com"));
+    team.members.push(member("dave@acme.com"));
+
+    let err = team.validate_with(&registry).unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["name"][0].code, "taken");
+    assert_eq!(errs["contact"][0].code, "owner_email");
+    assert_eq!(errs["__all__"][0].code, "too_many_members");
+    match err.errors()["members"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list.len(), 1);
+            assert_eq!(list[&1].field_errors()["email"][0].code, "domain");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[test]
+fn nested_structs_are_given_the_context() {
+    let registry = registry();
+    let mut team = valid_team();
+    team.owner.email = "bob@example.com".to_string();
+
+    let err = team.validate_with(&registry).unwrap_err();
+    match err.errors()["owner"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["email"][0].code, "domain");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[test]
+fn context_struct_can_be_nested_without_context() {
+    #[derive(Debug, Validate)]
+    struct Child {
+        #[validate(length(min = 2))]
+        name: String,
+    }
+
+    #[derive(Debug, Validate)]
+    #[validate(context = "Registry")]
+    struct Parent {
+        #[validate(custom = "name_available")]
+        name: String,
+        #[validate]
+        child: Child,
+    }
+
+    let parent = Parent { name: "root".to_string(), child: Child { name: "b".to_string() } };
+    let err = parent.validate_with(&registry()).unwrap_err();
+    assert_eq!(err.field_errors()["name"][0].code, "taken");
+    assert!(err.errors().contains_key("child"));
+}
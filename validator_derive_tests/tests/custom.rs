@@ -1,4 +1,4 @@
-use validator::{Validate, ValidationError};
+use validator::{Validate, ValidationError, ValidationErrors, ValidationErrorsKind};
 
 fn valid_custom_fn(_: &str) -> Result<(), ValidationError> {
     Ok(())
@@ -8,6 +8,37 @@ fn invalid_custom_fn(_: &str) -> Result<(), ValidationError> {
     Err(ValidationError::new("meh"))
 }
 
+fn password_fn(password: &str) -> Result<(), Vec<ValidationError>> {
+    let mut errors = vec![];
+    if !password.chars().any(|c| c.is_ascii_digit()) {
+        errors.push(ValidationError::new("no_digit"));
+    }
+    if !password.chars().any(|c| c.is_ascii_uppercase()) {
+        errors.push(ValidationError::new("no_uppercase"));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn address_fn(address: &str) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    let mut parts = address.splitn(2, ',');
+    if parts.next().map_or(true, |street| street.trim().is_empty()) {
+        errors.add("street", ValidationError::new("required"));
+    }
+    if parts.next().map_or(true, |city| city.trim().is_empty()) {
+        errors.add("city", ValidationError::new("required"));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 #[test]
 fn can_validate_custom_fn_ok() {
     #[derive(Debug, Validate)]
@@ -56,3 +87,116 @@ fn can_specify_message_for_custom_fn() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn can_return_several_errors_from_custom_fn() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(min = 8), custom(function = "password_fn", message = "weak"))]
+        val: String,
+    }
+
+    assert!(TestStruct { val: "Passw0rd".to_string() }.validate().is_ok());
+
+    let err = TestStruct { val: "pass".to_string() }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 3);
+    assert_eq!(errs["val"][0].code, "length");
+    assert_eq!(errs["val"][1].code, "no_digit");
+    assert_eq!(errs["val"][2].code, "no_uppercase");
+    // every error gets the message and the value
+    assert_eq!(errs["val"][2].message.as_deref(), Some("weak"));
+    assert_eq!(errs["val"][2].params["value"], "pass");
+}
+
+#[test]
+fn an_empty_error_from_custom_fn_still_fails() {
+    fn no_errors(_: &str) -> Result<(), Vec<ValidationError>> {
+        Err(vec![])
+    }
+
+    fn no_nested_errors(_: &str) -> Result<(), ValidationErrors> {
+        Err(ValidationErrors::new())
+    }
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom = "no_errors")]
+        val: String,
+        #[validate(custom(
+            function = "no_nested_errors",
+            code = "invalid_address",
+            message = "oops"
+        ))]
+        address: String,
+    }
+
+    let err = TestStruct { val: "a".to_string(), address: "b".to_string() }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "custom");
+    assert_eq!(errs["val"][0].params["value"], "a");
+    assert_eq!(errs["address"][0].code, "invalid_address");
+    assert_eq!(errs["address"][0].message.as_deref(), Some("oops"));
+}
+
+#[test]
+fn can_return_nested_errors_from_custom_fn() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom = "address_fn", length(min = 8))]
+        val: String,
+    }
+
+    assert!(TestStruct { val: "1 Main St, Springfield".to_string() }.validate().is_ok());
+
+    let err = TestStruct { val: ", ".to_string() }.validate().unwrap_err();
+    match err.errors()["val"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            let errs = errors.field_errors();
+            assert_eq!(errs["street"][0].code, "required");
+            assert_eq!(errs["city"][0].code, "required");
+            assert_eq!(errs["city"][0].params["value"], ", ");
+            // the other errors of the field are added to `__all__`
+            assert_eq!(errs["__all__"][0].code, "length");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[derive(Debug, Validate)]
+struct Booking {
+    start: u32,
+    #[validate(custom(function = "after_start", with_parent, message = "ends before it starts"))]
+    end: u32,
+    #[validate(custom(function = "within_booking", with_parent = true))]
+    checkout: Option<u32>,
+}
+
+fn after_start(end: u32, booking: &Booking) -> Result<(), ValidationError> {
+    if end < booking.start {
+        return Err(ValidationError::new("after_start"));
+    }
+    Ok(())
+}
+
+fn within_booking(checkout: u32, booking: &Booking) -> Result<(), ValidationError> {
+    if checkout < booking.start || checkout > booking.end {
+        return Err(ValidationError::new("within_booking"));
+    }
+    Ok(())
+}
+
+#[test]
+fn can_validate_custom_fn_with_parent() {
+    assert!(Booking { start: 1, end: 3, checkout: Some(2) }.validate().is_ok());
+    assert!(Booking { start: 1, end: 3, checkout: None }.validate().is_ok());
+
+    let err = Booking { start: 3, end: 1, checkout: Some(2) }.validate().unwrap_err();
+    let errs = err.field_errors();
+    // the errors are on the fields, not in `__all__`
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs["end"][0].code, "after_start");
+    assert_eq!(errs["end"][0].message.as_deref(), Some("ends before it starts"));
+    assert_eq!(errs["end"][0].params["value"], 1);
+    assert_eq!(errs["checkout"][0].code, "within_booking");
+}
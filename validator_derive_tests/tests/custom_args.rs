@@ -116,3 +116,29 @@ fn validate_custom_multiple_fn_with_args_ok() {
     assert!(s.validate_args((&mut cs, (123, 456))).is_err());
     assert!(cs.counter == 1);
 }
+
+fn below_max_with_parent(
+    value: i32,
+    parent: &ParentStruct,
+    max: i32,
+) -> Result<(), ValidationError> {
+    if value + parent.offset > max {
+        return Err(ValidationError::new("too_big"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Validate)]
+struct ParentStruct {
+    offset: i32,
+    #[validate(custom(function = "below_max_with_parent", with_parent, arg = "i32"))]
+    value: i32,
+}
+
+#[test]
+fn validate_custom_fn_with_parent_and_arg() {
+    let s = ParentStruct { offset: 5, value: 5 };
+    assert!(s.validate_args(10).is_ok());
+    let err = s.validate_args(9).unwrap_err();
+    assert_eq!(err.field_errors()["value"][0].code, "too_big");
+}
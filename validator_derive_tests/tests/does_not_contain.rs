@@ -91,3 +91,33 @@ fn can_specify_message_for_does_not_contain() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn collection_containing_needle_fails_validation() {
+    use std::collections::BTreeSet;
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(does_not_contain = "admin")]
+        roles: Vec<String>,
+        #[validate(does_not_contain(pattern = 0, code = "no_zero"))]
+        ids: Option<BTreeSet<i64>>,
+    }
+
+    let s =
+        TestStruct { roles: vec!["user".to_string()], ids: Some(vec![1].into_iter().collect()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        roles: vec!["user".to_string(), "admin".to_string()],
+        ids: Some(vec![0].into_iter().collect()),
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["roles"][0].code, "does_not_contain");
+    assert_eq!(errs["roles"][0].params["needle"], "admin");
+    assert_eq!(errs["ids"][0].code, "no_zero");
+    assert_eq!(errs["ids"][0].params["needle"], 0);
+}
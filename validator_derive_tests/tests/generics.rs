@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use serde::Serialize;
+use validator::{Validate, ValidateNested, ValidateWith, ValidationError, ValidationErrorsKind};
+
+#[derive(Debug, Serialize, Validate)]
+struct Item {
+    #[validate(length(min = 2))]
+    name: String,
+}
+
+fn item(name: &str) -> Item {
+    Item { name: name.to_string() }
+}
+
+// No bound is needed on `T`, the derive infers the ones of the validations
+#[derive(Debug, Validate)]
+struct Page<T> {
+    #[validate(range(min = 1))]
+    number: u32,
+    #[validate(length(max = 3))]
+    #[validate]
+    items: Vec<T>,
+    #[validate]
+    first: Option<Box<T>>,
+}
+
+#[test]
+fn infers_the_bounds_of_nested_fields() {
+    let page = Page { number: 1, items: vec![item("bob")], first: Some(Box::new(item("bob"))) };
+    assert!(page.validate().is_ok());
+
+    let page =
+        Page { number: 1, items: vec![item("bob"), item("b")], first: Some(Box::new(item("b"))) };
+    let err = page.validate().unwrap_err();
+    assert!(err.errors().contains_key("items"));
+    match err.errors()["first"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[test]
+fn generic_structs_compose() {
+    #[derive(Debug, Validate)]
+    struct Book<T> {
+        #[validate]
+        pages: HashMap<u32, Page<T>>,
+    }
+
+    let mut pages = HashMap::new();
+    pages.insert(1, Page { number: 0, items: vec![item("bob")], first: None });
+    let err = Book { pages }.validate().unwrap_err();
+    assert!(err.errors().contains_key("pages"));
+}
+
+fn not_default<T: Default + PartialEq>(value: &T) -> Result<(), ValidationError> {
+    if *value == T::default() {
+        return Err(ValidationError::new("default"));
+    }
+    Ok(())
+}
+
+#[test]
+fn infers_the_bounds_of_custom_and_must_match() {
+    // `T: Serialize` for the value param of the errors and `T: Eq` to compare the fields are
+    // inferred, the bounds of the function have to be given
+    #[derive(Debug, Validate)]
+    struct Confirm<T: Default + PartialEq> {
+        #[validate(custom = "not_default")]
+        value: Option<T>,
+        #[validate(must_match = "confirmation")]
+        original: T,
+        confirmation: T,
+    }
+
+    let confirm = Confirm { value: Some(1), original: 2, confirmation: 2 };
+    assert!(confirm.validate().is_ok());
+
+    let confirm = Confirm { value: Some(0), original: 2, confirmation: 3 };
+    let err = confirm.validate().unwrap_err();
+    assert_eq!(err.field_errors()["value"][0].code, "default");
+    assert_eq!(err.field_errors()["original"][0].code, "must_match");
+}
+
+struct Limits {
+    min_len: usize,
+}
+
+fn long_enough(name: &str, limits: &Limits) -> Result<(), ValidationError> {
+    if name.len() < limits.min_len {
+        return Err(ValidationError::new("too_short"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Validate)]
+#[validate(context = "Limits")]
+struct LimitedItem {
+    #[validate(custom = "long_enough")]
+    name: String,
+}
+
+#[test]
+fn infers_the_bounds_with_a_context() {
+    #[derive(Validate)]
+    #[validate(context = "Limits")]
+    struct Wrapper<T> {
+        #[validate(nested(context))]
+        items: Vec<T>,
+    }
+
+    let wrapper = Wrapper { items: vec![LimitedItem { name: "bob".to_string() }] };
+    assert!(wrapper.validate_with(&Limits { min_len: 3 }).is_ok());
+    assert!(wrapper.validate_with(&Limits { min_len: 4 }).is_err());
+}
+
+#[test]
+fn bound_replaces_the_inferred_bounds() {
+    trait Named {
+        fn name(&self) -> &str;
+    }
+
+    impl Named for Item {
+        fn name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    fn named<T: Named + Debug>(items: &[T]) -> Result<(), ValidationError> {
+        if items.iter().any(|item| item.name().is_empty()) {
+            return Err(ValidationError::new("unnamed"));
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    #[validate(bound = "T: Named + Debug + Serialize + ValidateNested<'v_a, Args = ()>")]
+    struct Roster<T> {
+        #[validate(custom = "named")]
+        #[validate]
+        items: Vec<T>,
+    }
+
+    let roster = Roster { items: vec![item("")] };
+    assert_eq!(roster.validate().unwrap_err().field_errors()["items"][0].code, "unnamed");
+}
+
+#[test]
+fn infers_the_bounds_of_length_and_range_on_type_parameters() {
+    // `for<'a> &'a L: HasLen` and `R: Into<f64> + Copy` replace the checks of the field types
+    #[derive(Debug, Validate)]
+    struct Bounded<L, R> {
+        #[validate(length(min = 1))]
+        value: L,
+        #[validate(length(max = 3, mode = "bytes"))]
+        short: Option<L>,
+        #[validate(range(min = 1, max = 10))]
+        number: R,
+        #[validate(range(max = 10))]
+        maybe: Option<R>,
+    }
+
+    let bounded = Bounded { value: vec![1], short: Some(vec![1, 2]), number: 5u8, maybe: Some(5) };
+    assert!(bounded.validate().is_ok());
+
+    let bounded = Bounded {
+        value: String::new(),
+        short: Some("long".to_string()),
+        number: 0.5f32,
+        maybe: Some(11.0),
+    };
+    let err = bounded.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["value"][0].code, "length");
+    assert_eq!(errs["short"][0].code, "length");
+    assert_eq!(errs["number"][0].code, "range");
+    assert_eq!(errs["maybe"][0].code, "range");
+}
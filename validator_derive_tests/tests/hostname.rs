@@ -0,0 +1,119 @@
+use validator::Validate;
+
+#[test]
+fn can_validate_valid_hostname() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(hostname)]
+        val: String,
+    }
+
+    let s = TestStruct { val: "localhost".to_string() };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn bad_hostname_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(hostname)]
+        val: String,
+    }
+
+    let s = TestStruct { val: "-bob.com".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "hostname");
+    assert_eq!(errs["val"][0].params["value"], "-bob.com");
+}
+
+#[test]
+fn can_specify_code_for_hostname() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(hostname(code = "oops"))]
+        val: String,
+    }
+    let s = TestStruct { val: "bob_".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "oops");
+}
+
+#[test]
+fn can_validate_valid_domain() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(domain)]
+        val: String,
+    }
+
+    let s = TestStruct { val: "bob.com".to_string() };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn bad_domain_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(domain)]
+        val: String,
+    }
+
+    let s = TestStruct { val: "*.bob.com".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "domain");
+    assert_eq!(errs["val"][0].params["value"], "*.bob.com");
+}
+
+#[test]
+fn can_validate_wildcard_domain() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(domain(wildcard = true))]
+        val: Option<String>,
+    }
+
+    let s = TestStruct { val: Some("*.bob.com".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: Some("*.com".to_string()) };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn can_validate_public_suffix_domain() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(domain(public_suffix = true, message = "not a registrable domain"))]
+        val: String,
+    }
+
+    let s = TestStruct { val: "bob.co.uk".to_string() };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "co.uk".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "domain");
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "not a registrable domain");
+}
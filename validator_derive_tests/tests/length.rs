@@ -222,3 +222,87 @@ fn can_validate_set_ref_for_length() {
     assert_eq!(errs["val"][0].params["min"], 5);
     assert_eq!(errs["val"][0].params["max"], 10);
 }
+
+#[test]
+fn can_validate_length_in_bytes() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(max = 4, mode = "bytes"))]
+        val: String,
+    }
+
+    let s = TestStruct { val: "日本".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(errs.contains_key("val"));
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "length");
+    assert_eq!(errs["val"][0].params["max"], 4);
+    assert_eq!(errs["val"][0].params["mode"], "bytes");
+
+    let s = TestStruct { val: "abcd".to_string() };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn can_validate_length_in_graphemes() {
+    #[derive(Debug, Validate)]
+    struct TestStruct<'a> {
+        #[validate(length(equal = 1, mode = "graphemes"))]
+        val: &'a str,
+        #[validate(length(max = 2, mode = "utf16"))]
+        opt: Option<String>,
+    }
+
+    let s = TestStruct { val: "e\u{301}", opt: Some("\u{1F600}".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "ab", opt: Some("\u{1F600}a".to_string()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].params["mode"], "graphemes");
+    assert_eq!(errs["opt"][0].params["mode"], "utf16");
+}
+
+#[test]
+fn can_validate_length_in_chars_for_cow() {
+    use std::borrow::Cow;
+
+    #[derive(Debug, Validate)]
+    struct TestStruct<'a> {
+        #[validate(length(max = 2))]
+        val: Cow<'a, str>,
+        #[validate(length(max = 2, mode = "chars"))]
+        val2: Cow<'a, str>,
+    }
+
+    let s = TestStruct { val: Cow::from("日本"), val2: Cow::from("日本") };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn can_validate_length_with_mode_for_any_string_path() {
+    #[derive(Debug, Validate)]
+    struct TestStruct<'a1> {
+        #[validate(length(max = 2, mode = "bytes"))]
+        cow: std::borrow::Cow<'a1, str>,
+        #[validate(length(max = 2, mode = "bytes"))]
+        boxed: Box<str>,
+        #[validate(length(max = 2, mode = "bytes"))]
+        opt: Option<std::string::String>,
+    }
+
+    let s = TestStruct { cow: "ab".into(), boxed: "ab".into(), opt: Some("ab".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { cow: "日".into(), boxed: "日".into(), opt: Some("日".to_string()) };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["cow"][0].params["mode"], "bytes");
+    assert_eq!(errs["boxed"][0].params["mode"], "bytes");
+    assert_eq!(errs["opt"][0].params["mode"], "bytes");
+}
@@ -0,0 +1,131 @@
+use validator::{Validate, ValidationErrors, ValidationErrorsKind};
+
+#[derive(Debug, Validate)]
+struct Comment {
+    #[validate(length(min = 1))]
+    text: String,
+    #[validate(nested(max_depth = 3))]
+    replies: Vec<Comment>,
+}
+
+#[derive(Debug, Validate)]
+struct Category {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(nested(max_depth = 2))]
+    parent: Option<Box<Category>>,
+}
+
+fn comment(text: &str, replies: Vec<Comment>) -> Comment {
+    Comment { text: text.to_string(), replies }
+}
+
+/// A thread of replies to replies
+fn thread(depth: usize) -> Comment {
+    (0..depth).fold(comment("last", vec![]), |reply, _| comment("reply", vec![reply]))
+}
+
+fn first_reply(errors: &ValidationErrors) -> &ValidationErrors {
+    match errors.errors()["replies"] {
+        ValidationErrorsKind::List(ref list) => &list[&0],
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[test]
+fn validates_up_to_the_max_depth() {
+    assert!(thread(3).validate().is_ok());
+
+    let mut root = thread(3);
+    root.replies[0].replies[0].replies[0].text = String::new();
+    let err = root.validate().unwrap_err();
+    let deepest = first_reply(first_reply(first_reply(&err)));
+    assert_eq!(deepest.field_errors()["text"][0].code, "length");
+}
+
+#[test]
+fn deeper_values_get_a_max_depth_error() {
+    let err = thread(4).validate().unwrap_err();
+    let too_deep = first_reply(first_reply(first_reply(first_reply(&err))));
+    let errs = too_deep.field_errors();
+    assert_eq!(errs["__all__"][0].code, "max_depth");
+    assert_eq!(errs["__all__"][0].params["max_depth"], 3);
+}
+
+#[test]
+fn does_not_recurse_without_bound() {
+    let err = thread(10_000).validate().unwrap_err();
+    assert!(err.errors().contains_key("replies"));
+    // the depth is reset between validations
+    assert!(thread(3).validate().is_ok());
+}
+
+#[test]
+fn sibling_fields_have_their_own_limit() {
+    #[derive(Debug, Validate)]
+    struct Node {
+        #[validate(length(min = 1))]
+        name: String,
+        #[validate(nested(max_depth = 1))]
+        left: Option<Box<Node>>,
+        #[validate(nested(max_depth = 3))]
+        right: Option<Box<Node>>,
+    }
+
+    fn node(left: Option<Node>, right: Option<Node>) -> Node {
+        Node { name: "node".to_string(), left: left.map(Box::new), right: right.map(Box::new) }
+    }
+
+    let right = (0..2).fold(node(None, None), |child, _| node(None, Some(child)));
+    let tree = node(Some(node(None, None)), Some(right));
+    assert!(tree.validate().is_ok());
+
+    // a left child can't have a left child but can have 3 levels of right children
+    let right = (0..2).fold(node(None, None), |child, _| node(None, Some(child)));
+    let tree = node(Some(node(Some(node(None, None)), Some(right))), None);
+    let err = tree.validate().unwrap_err();
+    let left = match err.errors()["left"] {
+        ValidationErrorsKind::Struct(ref left) => left,
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    };
+    assert_eq!(left.errors().len(), 1);
+    assert_eq!(left.field_errors()["left"][0].code, "max_depth");
+    assert_eq!(left.field_errors()["left"][0].params["max_depth"], 1);
+}
+
+#[test]
+fn the_limit_only_applies_to_its_field() {
+    #[derive(Debug, Validate)]
+    struct Forum {
+        #[validate(nested(max_depth = 1))]
+        threads: Vec<Comment>,
+    }
+
+    // the replies are limited by their own field, not by the one of the forum
+    let forum = Forum { threads: vec![thread(3)] };
+    assert!(forum.validate().is_ok());
+
+    let forum = Forum { threads: vec![thread(4)] };
+    let err = forum.validate().unwrap_err();
+    match err.errors()["threads"] {
+        ValidationErrorsKind::List(ref list) => {
+            let too_deep = first_reply(first_reply(first_reply(first_reply(&list[&0]))));
+            assert_eq!(too_deep.field_errors()["__all__"][0].params["max_depth"], 3);
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[test]
+fn applies_through_wrappers() {
+    let category = (0..3).fold(Category { name: "root".to_string(), parent: None }, |parent, i| {
+        Category { name: format!("sub{}", i), parent: Some(Box::new(parent)) }
+    });
+    let err = category.validate().unwrap_err();
+    let parent = |errors: &ValidationErrors| match errors.errors()["parent"] {
+        ValidationErrorsKind::Struct(ref parent) => parent.clone(),
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    };
+    let too_deep = parent(&parent(&err));
+    assert_eq!(too_deep.field_errors()["parent"][0].code, "max_depth");
+}
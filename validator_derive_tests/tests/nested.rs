@@ -612,7 +612,7 @@ fn test_field_validations_take_priority_over_nested_validations() {
 }
 
 #[test]
-#[should_panic(expected = "Attempt to replace non-empty ValidationErrors entry")]
+#[should_panic(expected = "cannot merge a list of errors with other errors at `child`")]
 #[allow(unused)]
 fn test_field_validation_errors_replaced_with_nested_validations_fails() {
     #[derive(Debug)]
@@ -708,8 +708,8 @@ fn test_field_validations_evaluated_after_nested_validations_fails() {
 
 fn unwrap_map<F>(errors: &ValidationErrors, f: F)
 where
-    F: FnOnce(HashMap<&'static str, ValidationErrorsKind>),
+    F: FnOnce(HashMap<Cow<'static, str>, ValidationErrorsKind>),
 {
     let errors = errors.clone();
-    f(errors.errors().clone());
+    f(errors.into_errors().into_iter().collect());
 }
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use validator::{Validate, ValidateArgs, ValidationError, ValidationErrorsKind};
+
+struct Tenant {
+    domain: &'static str,
+    max_items: u64,
+}
+
+fn in_tenant_domain(email: &str, tenant: &Tenant) -> Result<(), ValidationError> {
+    if !email.ends_with(tenant.domain) {
+        return Err(ValidationError::new("tenant_domain"));
+    }
+    Ok(())
+}
+
+fn below_max_items(items: &[Member], tenant: &Tenant) -> Result<(), ValidationError> {
+    if items.len() as u64 > tenant.max_items {
+        return Err(ValidationError::new("too_many"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Validate)]
+struct Member {
+    #[validate(custom(function = "in_tenant_domain", arg = "&'v_a Tenant"))]
+    email: String,
+}
+
+#[derive(Debug, Validate)]
+struct Team {
+    #[validate(nested(arg = "&'v_a Tenant"))]
+    owner: Member,
+    #[validate(nested(arg = "&'v_a Tenant"))]
+    deputy: Option<Member>,
+    #[validate(custom(function = "below_max_items", arg = "&'v_a Tenant"))]
+    #[validate(nested(arg = "&'v_a Tenant"))]
+    members: Vec<Member>,
+    #[validate(nested(arg = "&'v_a Tenant"))]
+    by_role: HashMap<String, Member>,
+}
+
+fn member(email: &str) -> Member {
+    Member { email: email.to_string() }
+}
+
+#[test]
+fn forwards_the_args_to_nested_structs() {
+    let tenant = Tenant { domain: "@acme.com", max_items: 2 };
+    let team = Team {
+        owner: member("bob@acme.com"),
+        deputy: Some(member("alice@acme.com")),
+        members: vec![member("carol@acme.com")],
+        by_role: HashMap::new(),
+    };
+    // The nested fields share the argument of `custom` with the same type
+    assert!(team.validate_args(&tenant).is_ok());
+
+    let other = Tenant { domain: "@example.com", max_items: 0 };
+    let err = team.validate_args(&other).unwrap_err();
+    match err.errors()["owner"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["email"][0].code, "tenant_domain");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+    assert!(err.errors().contains_key("deputy"));
+    assert_eq!(err.field_errors()["members"][0].code, "too_many");
+}
+
+#[test]
+fn each_distinct_arg_is_given_once() {
+    fn at_most(members: &[Member], max: usize) -> Result<(), ValidationError> {
+        if members.len() > max {
+            return Err(ValidationError::new("too_many"));
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    struct Department {
+        #[validate(custom(function = "at_most", arg = "usize"))]
+        #[validate(nested(arg = "&'v_a Tenant"))]
+        members: Vec<Member>,
+        #[validate(nested(arg = "&'v_a Tenant"))]
+        head: Member,
+    }
+
+    let tenant = Tenant { domain: "@acme.com", max_items: 2 };
+    let department = Department {
+        members: vec![member("carol@acme.com"), member("dave@acme.com")],
+        head: member("bob@evil.com"),
+    };
+    let err = department.validate_args((1, &tenant)).unwrap_err();
+    assert_eq!(err.field_errors()["members"][0].code, "too_many");
+    match err.errors()["head"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["email"][0].code, "tenant_domain");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[test]
+fn forwards_the_args_through_options_and_collections() {
+    let tenant = Tenant { domain: "@acme.com", max_items: 5 };
+    let mut by_role = HashMap::new();
+    by_role.insert("admin".to_string(), member("eve@evil.com"));
+    let team = Team {
+        owner: member("bob@acme.com"),
+        deputy: Some(member("mallory@evil.com")),
+        members: vec![member("carol@acme.com"), member("dave@evil.com")],
+        by_role,
+    };
+
+    let err = team.validate_args(&tenant).unwrap_err();
+    match err.errors()["deputy"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["email"][0].code, "tenant_domain");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+    match err.errors()["members"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list.len(), 1);
+            assert_eq!(list[&1].field_errors()["email"][0].code, "tenant_domain");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+    match err.errors()["by_role"] {
+        ValidationErrorsKind::List(ref list) => assert_eq!(list.len(), 1),
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[test]
+fn nested_without_args() {
+    #[derive(Debug, Validate)]
+    struct Child {
+        #[validate(length(min = 2))]
+        name: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Parent {
+        #[validate(nested)]
+        child: Child,
+    }
+
+    assert!(Parent { child: Child { name: "bob".to_string() } }.validate().is_ok());
+    let err = Parent { child: Child { name: "b".to_string() } }.validate().unwrap_err();
+    assert!(err.errors().contains_key("child"));
+}
@@ -0,0 +1,346 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use serde::Serialize;
+use validator::{
+    validate_nested_args, Validate, ValidateArgs, ValidateNested, ValidationError,
+    ValidationErrors, ValidationErrorsKind,
+};
+
+type Children = Vec<Child>;
+
+#[derive(Debug, Serialize, Validate)]
+struct Child {
+    #[validate(length(min = 2))]
+    name: String,
+}
+
+#[derive(Debug, Validate)]
+struct Parent {
+    #[validate]
+    boxed: Box<Child>,
+    #[validate]
+    shared: Arc<Child>,
+    #[validate]
+    counted: Option<Rc<Child>>,
+    #[validate]
+    queue: VecDeque<Child>,
+    #[validate]
+    maybe_list: Option<Vec<Rc<Child>>>,
+    #[validate]
+    aliased: Children,
+    #[validate]
+    grid: Vec<Vec<Child>>,
+    #[validate]
+    by_name: BTreeMap<String, Arc<Child>>,
+}
+
+fn child(name: &str) -> Child {
+    Child { name: name.to_string() }
+}
+
+fn valid_parent() -> Parent {
+    Parent {
+        boxed: Box::new(child("bob")),
+        shared: Arc::new(child("alice")),
+        counted: Some(Rc::new(child("carol"))),
+        queue: vec![child("dave")].into(),
+        maybe_list: Some(vec![Rc::new(child("eve"))]),
+        aliased: vec![child("frank")],
+        grid: vec![vec![child("grace")]],
+        by_name: BTreeMap::new(),
+    }
+}
+
+#[test]
+fn is_ok_through_every_wrapper() {
+    assert!(valid_parent().validate().is_ok());
+}
+
+#[test]
+fn validates_through_pointers() {
+    let mut parent = valid_parent();
+    *parent.boxed = child("b");
+    parent.shared = Arc::new(child("a"));
+    parent.counted = Some(Rc::new(child("c")));
+
+    let err = parent.validate().unwrap_err();
+    match err.errors()["boxed"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+    match err.errors()["shared"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+    match err.errors()["counted"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[test]
+fn validates_through_collections() {
+    let mut parent = valid_parent();
+    parent.queue.push_back(child("d"));
+    parent.maybe_list = Some(vec![Rc::new(child("eve")), Rc::new(child("e"))]);
+    parent.aliased = vec![child("f")];
+    parent.by_name.insert("heidi".to_string(), Arc::new(child("h")));
+
+    let err = parent.validate().unwrap_err();
+    match err.errors()["queue"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&1].field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+    match err.errors()["maybe_list"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&1].field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+    match err.errors()["aliased"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&0].field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+    match err.errors()["by_name"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&0].field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[test]
+fn validates_through_boxed_collections() {
+    // The extra boxes are what is tested here
+    #[allow(clippy::box_collection, clippy::vec_box)]
+    #[derive(Debug, Validate)]
+    struct Family {
+        #[validate]
+        children: Option<Box<Vec<Child>>>,
+        #[validate]
+        boxed_children: Vec<Box<Child>>,
+        #[validate]
+        slice: Box<[Option<Child>]>,
+    }
+
+    let family = Family {
+        children: Some(Box::new(vec![child("bob")])),
+        boxed_children: vec![Box::new(child("alice"))],
+        slice: vec![None, Some(child("carol"))].into_boxed_slice(),
+    };
+    assert!(family.validate().is_ok());
+
+    let family = Family {
+        children: Some(Box::new(vec![child("bob"), child("b")])),
+        boxed_children: vec![Box::new(child("a"))],
+        slice: vec![None, Some(child("c"))].into_boxed_slice(),
+    };
+    let err = family.validate().unwrap_err();
+    match err.errors()["children"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&1].field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+    match err.errors()["boxed_children"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&0].field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+    match err.errors()["slice"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&1].field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[test]
+fn nested_lists_are_keyed_by_the_inner_index() {
+    let mut parent = valid_parent();
+    parent.grid = vec![vec![child("grace")], vec![child("heidi"), child("i")]];
+
+    let err = parent.validate().unwrap_err();
+    let row = match err.errors()["grid"] {
+        ValidationErrorsKind::List(ref list) => &list[&1],
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    };
+    match row.errors()["1"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["name"][0].code, "length");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[test]
+fn field_errors_take_priority_over_the_items() {
+    fn no_short_names(children: &VecDeque<Child>) -> Result<(), ValidationError> {
+        if children.iter().any(|c| c.name.len() < 2) {
+            return Err(ValidationError::new("short_names"));
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    struct Group {
+        #[validate(custom = "no_short_names")]
+        #[validate]
+        members: VecDeque<Child>,
+    }
+
+    let group = Group { members: vec![child("bob"), child("b")].into() };
+    let err = group.validate().unwrap_err();
+    assert_eq!(err.field_errors()["members"][0].code, "short_names");
+}
+
+#[test]
+fn can_nest_a_manual_implementation() {
+    struct Digit(u32);
+
+    impl Validate for Digit {
+        fn validate(&self) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            if self.0 > 9 {
+                errors.add("value", ValidationError::new("digit"));
+                return Err(errors);
+            }
+            Ok(())
+        }
+    }
+
+    #[derive(Validate)]
+    struct Numbers {
+        #[validate]
+        first: Digit,
+        #[validate]
+        numbers: Option<Vec<Arc<Digit>>>,
+        #[validate]
+        boxed: Option<Box<Digit>>,
+    }
+
+    let numbers = Numbers {
+        first: Digit(10),
+        numbers: Some(vec![Arc::new(Digit(2)), Arc::new(Digit(12))]),
+        boxed: Some(Box::new(Digit(11))),
+    };
+    let err = numbers.validate().unwrap_err();
+    match err.errors()["first"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["value"][0].code, "digit");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+    match err.errors()["numbers"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&1].field_errors()["value"][0].code, "digit");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+    match err.errors()["boxed"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["value"][0].code, "digit");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[test]
+fn can_nest_a_manual_implementation_with_arguments() {
+    struct Digit(u32);
+
+    impl<'v_a> ValidateArgs<'v_a> for Digit {
+        type Args = u32;
+
+        fn validate_args(&self, max: u32) -> Result<(), ValidationErrors> {
+            let mut errors = ValidationErrors::new();
+            if self.0 > max {
+                errors.add("value", ValidationError::new("digit"));
+                return Err(errors);
+            }
+            Ok(())
+        }
+    }
+
+    impl<'v_a> ValidateNested<'v_a> for Digit {
+        type Args = u32;
+
+        fn validate_nested(&self, max: u32) -> Result<(), ValidationErrorsKind> {
+            validate_nested_args(self, max)
+        }
+    }
+
+    #[derive(Validate)]
+    struct Numbers {
+        #[validate(nested(arg = "u32"))]
+        numbers: Vec<Digit>,
+    }
+
+    let numbers = Numbers { numbers: vec![Digit(2), Digit(5)] };
+    assert!(numbers.validate_args(3).is_err());
+    let err = numbers.validate_args(3).unwrap_err();
+    match err.errors()["numbers"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&1].field_errors()["value"][0].code, "digit");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+    assert!(numbers.validate_args(9).is_ok());
+}
+
+#[test]
+fn validates_boxed_structs_with_arguments() {
+    fn at_most(value: u32, max: u32) -> Result<(), ValidationError> {
+        if value > max {
+            return Err(ValidationError::new("too_big"));
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    struct Limited {
+        #[validate(custom(function = "at_most", arg = "u32"))]
+        value: u32,
+    }
+
+    #[allow(clippy::vec_box)]
+    #[derive(Debug, Validate)]
+    struct Limits {
+        #[validate(nested(arg = "u32"))]
+        items: Vec<Box<Limited>>,
+        #[validate(nested(arg = "u32"))]
+        first: Option<Box<Limited>>,
+    }
+
+    let limits = Limits {
+        items: vec![Box::new(Limited { value: 1 }), Box::new(Limited { value: 5 })],
+        first: Some(Box::new(Limited { value: 4 })),
+    };
+    assert!(limits.validate_args(5).is_ok());
+    let err = limits.validate_args(3).unwrap_err();
+    match err.errors()["items"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&1].field_errors()["value"][0].code, "too_big");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+    match err.errors()["first"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["value"][0].code, "too_big");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
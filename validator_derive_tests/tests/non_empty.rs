@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use validator::Validate;
+
+#[test]
+fn can_validate_non_empty_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(non_empty)]
+        val: String,
+        #[validate(non_empty)]
+        list: Vec<u8>,
+        #[validate(non_empty)]
+        map: Option<HashMap<String, u8>>,
+    }
+
+    let s = TestStruct { val: " ".to_string(), list: vec![0], map: None };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn empty_values_fail_non_empty() {
+    #[derive(Debug, Validate)]
+    struct TestStruct<'a> {
+        #[validate(non_empty)]
+        val: Cow<'a, str>,
+        #[validate(non_empty(code = "no_items"))]
+        list: &'a [u8],
+        #[validate(non_empty(message = "pick at least one"))]
+        map: Option<HashMap<String, u8>>,
+    }
+
+    let s = TestStruct { val: Cow::from(""), list: &[], map: Some(HashMap::new()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs["val"][0].code, "non_empty");
+    assert_eq!(errs["val"][0].params["value"], "");
+    assert_eq!(errs["list"][0].code, "no_items");
+    assert_eq!(errs["map"][0].code, "non_empty");
+    assert_eq!(errs["map"][0].clone().message.unwrap(), "pick at least one");
+}
+
+#[test]
+fn blank_values_fail_not_blank() {
+    #[derive(Debug, Validate)]
+    struct TestStruct<'a> {
+        #[validate(not_blank)]
+        val: String,
+        #[validate(not_blank(code = "blank"))]
+        name: Option<&'a str>,
+    }
+
+    let s = TestStruct { val: " a ".to_string(), name: None };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "\u{3000}\n".to_string(), name: Some("") };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"][0].code, "not_blank");
+    assert_eq!(errs["val"][0].params["value"], "\u{3000}\n");
+    assert_eq!(errs["name"][0].code, "blank");
+}
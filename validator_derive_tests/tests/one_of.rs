@@ -0,0 +1,132 @@
+use serde::Serialize;
+use validator::Validate;
+
+const STATUSES: &[&str] = &["draft", "published", "archived"];
+
+#[derive(Debug, PartialEq, Serialize)]
+enum Role {
+    User,
+    Admin,
+}
+
+const ASSIGNABLE_ROLES: [Role; 1] = [Role::User];
+
+#[test]
+fn can_validate_one_of_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of("draft", "published", "archived"))]
+        status: String,
+        #[validate(one_of(path = "crate::STATUSES"))]
+        previous_status: Option<String>,
+        #[validate(one_of(1, 2, 4, 8))]
+        size: u32,
+        #[validate(one_of(0.5, 1.0))]
+        ratio: Option<f64>,
+        #[validate(one_of(path = "crate::ASSIGNABLE_ROLES"))]
+        role: Role,
+    }
+
+    let s = TestStruct {
+        status: "draft".to_string(),
+        previous_status: Some("archived".to_string()),
+        size: 4,
+        ratio: Some(0.5),
+        role: Role::User,
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        status: "draft".to_string(),
+        previous_status: None,
+        size: 1,
+        ratio: None,
+        role: Role::User,
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn value_not_in_one_of_fails_validation() {
+    #[derive(Debug, Validate)]
+    struct TestStruct<'a> {
+        #[validate(one_of("draft", "published", "archived"))]
+        status: &'a str,
+        #[validate(one_of(path = "crate::STATUSES"))]
+        previous_status: Option<&'a str>,
+        #[validate(one_of(1, 2, 4, 8))]
+        size: Option<u32>,
+        #[validate(one_of(path = "crate::ASSIGNABLE_ROLES"))]
+        role: Role,
+    }
+
+    let s = TestStruct {
+        status: "deleted",
+        previous_status: Some("deleted"),
+        size: Some(3),
+        role: Role::Admin,
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 4);
+    assert_eq!(errs["status"].len(), 1);
+    assert_eq!(errs["status"][0].code, "one_of");
+    assert_eq!(errs["status"][0].params["value"], "deleted");
+    assert_eq!(
+        errs["status"][0].params["values"],
+        serde_json::json!(["draft", "published", "archived"])
+    );
+    assert_eq!(
+        errs["previous_status"][0].params["values"],
+        serde_json::json!(["draft", "published", "archived"])
+    );
+    assert_eq!(errs["size"][0].params["value"], 3);
+    assert_eq!(errs["size"][0].params["values"], serde_json::json!([1, 2, 4, 8]));
+    assert_eq!(errs["role"][0].params["value"], "Admin");
+    assert_eq!(errs["role"][0].params["values"], serde_json::json!(["User"]));
+}
+
+#[test]
+fn can_validate_none_of() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(none_of("admin", "root"))]
+        username: String,
+        #[validate(none_of(0))]
+        count: Option<Option<i32>>,
+    }
+
+    let s = TestStruct { username: "bob".to_string(), count: Some(Some(1)) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { username: "root".to_string(), count: Some(Some(0)) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["username"].len(), 1);
+    assert_eq!(errs["username"][0].code, "none_of");
+    assert_eq!(errs["username"][0].params["value"], "root");
+    assert_eq!(errs["username"][0].params["values"], serde_json::json!(["admin", "root"]));
+    assert_eq!(errs["count"][0].code, "none_of");
+}
+
+#[test]
+fn can_specify_code_and_message_for_one_of() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of("a", "b", code = "oops", message = "not allowed"))]
+        val: String,
+    }
+
+    let s = TestStruct { val: "c".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "oops");
+    assert_eq!(errs["val"][0].clone().message.unwrap(), "not allowed");
+}
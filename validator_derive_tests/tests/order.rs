@@ -0,0 +1,100 @@
+use validator::{Validate, ValidationError};
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 5, message = "zip too short"))]
+    zip: String,
+    #[validate(length(min = 2, message = "city too short"))]
+    city: String,
+}
+
+#[derive(Debug, Validate)]
+struct Contact {
+    #[validate(email(message = "invalid email"))]
+    email: String,
+}
+
+fn always_invalid(_: &Signup) -> Result<(), ValidationError> {
+    let mut err = ValidationError::new("schema");
+    err.message = Some("invalid signup".into());
+    Err(err)
+}
+
+#[derive(Debug, Validate)]
+#[validate(schema(function = "always_invalid", skip_on_field_errors = false))]
+struct Signup {
+    #[validate(length(min = 2, message = "name too short"))]
+    name: String,
+    #[validate]
+    address: Address,
+    #[validate(range(min = 18, message = "too young"))]
+    age: u8,
+    #[validate]
+    contacts: Vec<Contact>,
+    #[validate(length(min = 8, message = "password too short"))]
+    password: String,
+}
+
+fn invalid_signup() -> Signup {
+    Signup {
+        name: "b".to_string(),
+        address: Address { zip: "123".to_string(), city: "P".to_string() },
+        age: 12,
+        contacts: vec![Contact { email: "bob".to_string() }],
+        password: "1234".to_string(),
+    }
+}
+
+#[test]
+fn errors_are_in_declaration_order() {
+    let errors = invalid_signup().validate().unwrap_err();
+    let fields: Vec<_> = errors.errors().keys().map(|k| k.as_ref()).collect();
+    assert_eq!(fields, vec!["name", "address", "age", "contacts", "password", "__all__"]);
+}
+
+#[test]
+fn display_is_in_declaration_order() {
+    let errors = invalid_signup().validate().unwrap_err();
+    assert_eq!(
+        errors.to_string(),
+        "name: name too short\n\
+         address.zip: zip too short\n\
+         address.city: city too short\n\
+         age: too young\n\
+         contacts[0].email: invalid email\n\
+         password: password too short\n\
+         __all__: invalid signup"
+    );
+}
+
+#[test]
+fn serialization_is_in_declaration_order() {
+    let errors = invalid_signup().validate().unwrap_err();
+    let json = serde_json::to_string(&errors).unwrap();
+
+    let positions: Vec<_> =
+        ["\"name\"", "\"address\"", "\"age\"", "\"contacts\"", "\"password\"", "\"__all__\""]
+            .iter()
+            .map(|field| json.find(field).unwrap())
+            .collect();
+    let mut sorted = positions.clone();
+    sorted.sort_unstable();
+    assert_eq!(positions, sorted);
+
+    // the params are in the order they were added too
+    let age = serde_json::to_string(&errors.field_errors()["age"][0].params).unwrap();
+    assert_eq!(age, r#"{"min":18.0,"value":12}"#);
+
+    // and it is the same every time
+    assert_eq!(json, serde_json::to_string(&invalid_signup().validate().unwrap_err()).unwrap());
+}
+
+#[test]
+fn display_separates_list_items() {
+    let mut signup = invalid_signup();
+    signup.contacts.push(Contact { email: "alice".to_string() });
+    let errors = signup.validate().unwrap_err();
+    assert!(errors
+        .to_string()
+        .contains("contacts[0].email: invalid email\ncontacts[1].email: invalid email\n"));
+}
@@ -1,9 +1,10 @@
 use lazy_static::lazy_static;
-use regex::Regex;
+use regex::{Regex, RegexSet};
 use validator::Validate;
 
 lazy_static! {
     static ref RE2: Regex = Regex::new(r"^[a-z]{2}$").unwrap();
+    static ref RESERVED: RegexSet = RegexSet::new([r"^admin", r"^root$", r"^[a-z]+$"]).unwrap();
 }
 
 #[test]
@@ -71,3 +72,115 @@ fn can_specify_message_for_regex() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn can_validate_inline_regex_pattern() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(regex(pattern = r"^[a-z]{2}$"))]
+        val: String,
+        #[validate(regex(pattern = "^[0-9]+$", code = "digits"))]
+        opt: Option<String>,
+    }
+
+    let s = TestStruct { val: "aa".to_string(), opt: Some("123".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "2".to_string(), opt: Some("12a".to_string()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "regex");
+    assert_eq!(errs["val"][0].params["value"], "2");
+    assert_eq!(errs["val"][0].params["pattern"], "^[a-z]{2}$");
+    assert_eq!(errs["opt"][0].code, "digits");
+    assert_eq!(errs["opt"][0].params["pattern"], "^[0-9]+$");
+}
+
+#[test]
+fn can_validate_negated_regex() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(regex(path = "crate::RE2", negate))]
+        val: String,
+        #[validate(regex(pattern = "^[0-9]+$", negate = true))]
+        opt: Option<String>,
+    }
+
+    let s = TestStruct { val: "abc".to_string(), opt: Some("12a".to_string()) };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "ab".to_string(), opt: Some("12".to_string()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["val"].len(), 1);
+    assert_eq!(errs["val"][0].code, "regex");
+    assert_eq!(errs["val"][0].params["value"], "ab");
+    assert_eq!(errs["opt"][0].params["pattern"], "^[0-9]+$");
+}
+
+#[test]
+fn can_validate_case_insensitive_regex() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(regex(pattern = "^[a-z]{2}$", case_insensitive))]
+        val: String,
+    }
+
+    let s = TestStruct { val: "aB".to_string() };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: "aB1".to_string() };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    // the pattern is reported as written in the attribute
+    assert_eq!(errs["val"][0].params["pattern"], "^[a-z]{2}$");
+}
+
+#[test]
+fn can_validate_regex_set() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(regex(path = "crate::RESERVED", set = "any"))]
+        any: String,
+        #[validate(regex(path = "crate::RESERVED", set = "all"))]
+        all: String,
+        #[validate(regex(path = "crate::RESERVED", set = "none"))]
+        none: Option<String>,
+    }
+
+    let s = TestStruct {
+        any: "admin1".to_string(),
+        all: "root".to_string(),
+        none: Some("Bob1".to_string()),
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert!(!errs.contains_key("any"));
+    assert!(!errs.contains_key("none"));
+    assert_eq!(errs["all"].len(), 1);
+    assert_eq!(errs["all"][0].code, "regex");
+    assert_eq!(errs["all"][0].params["matches"], serde_json::json!([1, 2]));
+
+    let s = TestStruct {
+        any: "Bob1".to_string(),
+        all: "Bob1".to_string(),
+        none: Some("admin".to_string()),
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["any"][0].params["matches"], serde_json::json!([]));
+    assert_eq!(errs["all"][0].params["matches"], serde_json::json!([]));
+    assert_eq!(errs["none"][0].params["matches"], serde_json::json!([0, 2]));
+    assert_eq!(errs["none"][0].params["value"], "admin");
+}
@@ -90,3 +90,45 @@ fn can_specify_message_for_required() {
     assert_eq!(errs["val"].len(), 1);
     assert_eq!(errs["val"][0].clone().message.unwrap(), "oops");
 }
+
+#[test]
+fn empty_or_blank_values_can_fail_required() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(required(non_empty))]
+        tags: Option<Vec<String>>,
+        #[validate(required(not_blank, code = "missing"))]
+        name: Option<String>,
+        #[validate(required)]
+        nickname: Option<String>,
+    }
+
+    let s = TestStruct {
+        tags: Some(vec!["a".to_string()]),
+        name: Some("Bob".to_string()),
+        nickname: Some("  ".to_string()),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { tags: Some(vec![]), name: Some(" \t".to_string()), nickname: None };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["tags"].len(), 1);
+    assert_eq!(errs["tags"][0].code, "required");
+    assert_eq!(errs["tags"][0].params["value"], serde_json::json!([]));
+    assert_eq!(errs["name"].len(), 1);
+    assert_eq!(errs["name"][0].code, "missing");
+    assert_eq!(errs["name"][0].params["value"], " \t");
+    assert_eq!(errs["nickname"][0].code, "required");
+
+    let s = TestStruct { tags: None, name: None, nickname: Some(String::new()) };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs["tags"].len(), 1);
+    assert_eq!(errs["name"].len(), 1);
+}
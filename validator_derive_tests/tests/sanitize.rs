@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use validator::{Sanitize, Validate};
+
+#[derive(Debug, Sanitize, Validate)]
+struct Address {
+    #[normalize(trim, collapse_whitespace)]
+    #[validate(length(min = 1))]
+    street: String,
+    #[normalize(trim, uppercase)]
+    country: Option<String>,
+}
+
+#[derive(Debug, Sanitize, Validate)]
+struct SignupData {
+    #[normalize(trim, lowercase)]
+    #[validate(email)]
+    email: String,
+    #[normalize(trim, nfc)]
+    name: String,
+    #[normalize(trim)]
+    tags: Vec<String>,
+    #[normalize(lowercase)]
+    labels: Option<HashMap<u32, String>>,
+    #[normalize]
+    #[validate]
+    address: Address,
+    #[normalize]
+    #[validate]
+    previous_addresses: Vec<Address>,
+    phone: String,
+}
+
+#[test]
+fn can_sanitize_fields_in_place() {
+    let mut s = SignupData {
+        email: "  Bob@Example.COM ".to_string(),
+        name: " Jose\u{301} ".to_string(),
+        tags: vec![" a".to_string(), "b ".to_string()],
+        labels: Some(vec![(1, "Primary".to_string())].into_iter().collect()),
+        address: Address {
+            street: " 1  Main\tStreet ".to_string(),
+            country: Some(" fr ".to_string()),
+        },
+        previous_addresses: vec![Address { street: " Old street".to_string(), country: None }],
+        phone: " 0123 ".to_string(),
+    };
+
+    s.sanitize();
+
+    assert_eq!(s.email, "bob@example.com");
+    assert_eq!(s.name, "Jos\u{e9}");
+    assert_eq!(s.tags, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(s.labels.unwrap()[&1], "primary");
+    assert_eq!(s.address.street, "1 Main Street");
+    assert_eq!(s.address.country.unwrap(), "FR");
+    assert_eq!(s.previous_addresses[0].street, "Old street");
+    // fields without #[normalize] are left untouched
+    assert_eq!(s.phone, " 0123 ");
+}
+
+#[test]
+fn can_sanitize_and_validate() {
+    let mut s = Address { street: " Main street ".to_string(), country: None };
+    assert!(s.sanitize_and_validate().is_ok());
+    assert_eq!(s.street, "Main street");
+
+    let mut s = Address { street: "   ".to_string(), country: None };
+    let res = s.sanitize_and_validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs["street"][0].code, "length");
+    assert_eq!(errs["street"][0].params["value"], "");
+}
+
+#[test]
+fn operations_are_applied_in_order() {
+    #[derive(Debug, Sanitize)]
+    struct TestStruct {
+        #[normalize(collapse_whitespace, trim)]
+        collapsed_then_trimmed: String,
+        #[normalize(trim)]
+        #[normalize(collapse_whitespace)]
+        trimmed_then_collapsed: String,
+    }
+
+    let mut s = TestStruct {
+        collapsed_then_trimmed: "  a  b  ".to_string(),
+        trimmed_then_collapsed: "  a  b  ".to_string(),
+    };
+    s.sanitize();
+    assert_eq!(s.collapsed_then_trimmed, "a b");
+    assert_eq!(s.trimmed_then_collapsed, "a b");
+}
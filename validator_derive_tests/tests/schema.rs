@@ -21,12 +21,14 @@ fn can_validate_schema_fn_ok() {
 mod some_defining_mod {
     use validator::Validate;
 
+    #[allow(dead_code)]
     #[derive(Debug, Validate)]
     #[validate(schema(function = "crate::some_validation_mod::valid_schema_fn"))]
     pub struct TestStructValid {
         pub val: String,
     }
 
+    #[allow(dead_code)]
     #[derive(Debug, Validate)]
     #[validate(schema(function = "crate::some_validation_mod::invalid_schema_fn"))]
     pub struct TestStructInvalid {
@@ -0,0 +1,174 @@
+use serde::Serialize;
+use validator::{Severity, Validate, ValidationError, ValidationErrors, ValidationErrorsKind};
+
+fn weak_password(password: &str) -> Result<(), ValidationError> {
+    if password.chars().all(char::is_alphanumeric) {
+        return Err(ValidationError::new("weak_password"));
+    }
+    Ok(())
+}
+
+fn disposable_email(email: &str) -> Result<(), ValidationError> {
+    if email.ends_with("@mailinator.com") {
+        return Err(ValidationError::warning("disposable_email"));
+    }
+    Ok(())
+}
+
+fn unusual_signup(signup: &Signup) -> Result<(), ValidationError> {
+    if signup.name.len() > 10 {
+        return Err(ValidationError::new("unusual"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Validate)]
+struct Address {
+    #[validate(length(min = 5), length(max = 5, severity = "warning", code = "long_zip"))]
+    zip: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(schema(function = "unusual_signup", severity = "warning"))]
+struct Signup {
+    #[validate(length(min = 2), length(max = 8, severity = "warning", message = "quite long"))]
+    name: String,
+    #[validate(email, custom = "disposable_email")]
+    email: String,
+    #[validate(
+        length(min = 4),
+        custom(function = "weak_password", severity = "warning", message = "weak password")
+    )]
+    password: String,
+    #[validate]
+    address: Address,
+    #[validate(length(max = 2, severity = "warning"))]
+    #[validate]
+    addresses: Vec<Address>,
+}
+
+fn valid_signup() -> Signup {
+    Signup {
+        name: "bob".to_string(),
+        email: "bob@example.com".to_string(),
+        password: "p@ssword".to_string(),
+        address: Address { zip: "12345".to_string() },
+        addresses: vec![],
+    }
+}
+
+#[test]
+fn is_ok_without_warnings() {
+    let signup = valid_signup();
+    assert!(signup.validate().is_ok());
+    assert!(signup.validate_with_warnings().unwrap().is_empty());
+}
+
+#[test]
+fn warnings_do_not_fail_the_validation() {
+    let signup = Signup {
+        name: "Bobby Tables".to_string(),
+        email: "bob@mailinator.com".to_string(),
+        password: "password".to_string(),
+        address: Address { zip: "123456".to_string() },
+        addresses: vec![],
+    };
+    assert!(signup.validate().is_ok());
+
+    let warnings = signup.validate_with_warnings().unwrap();
+    assert!(!warnings.has_errors());
+    let errs = warnings.field_errors();
+    assert_eq!(errs["name"][0].code, "length");
+    assert_eq!(errs["name"][0].message.as_deref(), Some("quite long"));
+    assert_eq!(errs["name"][0].severity, Severity::Warning);
+    // a custom function can return a warning itself
+    assert_eq!(errs["email"][0].code, "disposable_email");
+    assert_eq!(errs["password"][0].code, "weak_password");
+    assert_eq!(errs["password"][0].message.as_deref(), Some("weak password"));
+    assert_eq!(errs["__all__"][0].code, "unusual");
+    assert!(errs["__all__"][0].is_warning());
+    // the warnings of nested structs too
+    match warnings.errors()["address"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["zip"][0].code, "long_zip");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[test]
+fn errors_fail_the_validation_without_the_warnings() {
+    let mut signup = valid_signup();
+    signup.name = "b".to_string();
+    signup.email = "bob@mailinator.com".to_string();
+    signup.address.zip = "123456".to_string();
+
+    let errors = signup.validate().unwrap_err();
+    assert!(errors.has_errors());
+    assert_eq!(errors.errors().len(), 1);
+    assert_eq!(errors.field_errors()["name"][0].code, "length");
+    assert_eq!(errors.field_errors()["name"][0].severity, Severity::Error);
+
+    // and with them
+    let errors = signup.validate_with_warnings().unwrap_err();
+    assert_eq!(errors.errors().len(), 3);
+    let (errors, warnings) = errors.split_warnings();
+    assert_eq!(errors.errors().len(), 1);
+    assert_eq!(warnings.field_errors()["email"][0].code, "disposable_email");
+    match warnings.errors()["address"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert!(errors.field_errors()["zip"][0].is_warning());
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[test]
+fn nested_errors_fail_the_validation() {
+    let mut signup = valid_signup();
+    signup.address.zip = "123".to_string();
+    let errors = signup.validate().unwrap_err();
+    match errors.errors()["address"] {
+        ValidationErrorsKind::Struct(ref errors) => {
+            assert_eq!(errors.field_errors()["zip"][0].code, "length");
+        }
+        ref kind => panic!("Expected a struct, got {:?}", kind),
+    }
+}
+
+#[test]
+fn warnings_on_a_collection_do_not_skip_its_items() {
+    let mut signup = valid_signup();
+    signup.addresses = vec![
+        Address { zip: "12345".to_string() },
+        Address { zip: "12345".to_string() },
+        Address { zip: "12345".to_string() },
+    ];
+    let warnings = signup.validate_with_warnings().unwrap();
+    assert!(warnings.field_errors()["addresses"][0].is_warning());
+
+    // the errors of the items take the place of the warnings of the field
+    signup.addresses[1].zip = "123".to_string();
+    let errors = signup.validate().unwrap_err();
+    match errors.errors()["addresses"] {
+        ValidationErrorsKind::List(ref list) => {
+            assert_eq!(list[&1].field_errors()["zip"][0].code, "length");
+        }
+        ref kind => panic!("Expected a list, got {:?}", kind),
+    }
+}
+
+#[test]
+fn only_warnings_are_serialized_with_a_severity() {
+    let mut signup = valid_signup();
+    signup.name = "b".to_string();
+    signup.email = "bob@mailinator.com".to_string();
+
+    let json = serde_json::to_value(signup.validate_with_warnings().unwrap_err()).unwrap();
+    assert!(json["name"][0].get("severity").is_none());
+    assert_eq!(json["email"][0]["severity"], "warning");
+
+    let errors: ValidationErrors = serde_json::from_value(json).unwrap();
+    assert!(errors.field_errors()["email"][0].is_warning());
+    assert!(!errors.field_errors()["name"][0].is_warning());
+}
@@ -0,0 +1,201 @@
+use validator::{Validate, ValidationError, ValidationErrorsKind};
+
+#[derive(Debug, Validate)]
+struct LineItem {
+    #[validate(length(min = 1))]
+    sku: String,
+}
+
+fn sku(item: &LineItem) -> &str {
+    &item.sku
+}
+
+#[allow(clippy::ptr_arg)]
+fn lowercase(email: &String) -> String {
+    email.to_lowercase()
+}
+
+#[test]
+fn can_validate_unique_ok() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(unique)]
+        emails: Vec<String>,
+        #[validate(unique(by = "crate::sku"))]
+        items: Option<Vec<LineItem>>,
+    }
+
+    let s = TestStruct {
+        emails: vec!["bob@example.com".to_string(), "alice@example.com".to_string()],
+        items: Some(vec![LineItem { sku: "A1".to_string() }, LineItem { sku: "B2".to_string() }]),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn duplicates_fail_validation_at_their_index() {
+    #[derive(Debug, Validate)]
+    struct TestStruct<'a> {
+        #[validate(unique(code = "duplicate_email", by = "crate::lowercase"))]
+        emails: Vec<String>,
+        #[validate(unique)]
+        ids: &'a [u32],
+    }
+
+    let s = TestStruct {
+        emails: vec![
+            "bob@example.com".to_string(),
+            "alice@example.com".to_string(),
+            "Bob@example.com".to_string(),
+        ],
+        ids: &[1, 2, 1, 1],
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let errs = res.unwrap_err().into_errors();
+
+    let emails = match errs["emails"] {
+        ValidationErrorsKind::List(ref list) => list,
+        ref kind => panic!("Expected a list of errors, found {:?}", kind),
+    };
+    assert_eq!(emails.keys().collect::<Vec<_>>(), vec![&2]);
+    let err = &emails[&2].field_errors()["__all__"][0];
+    assert_eq!(err.code, "duplicate_email");
+    assert_eq!(err.params["duplicate_of"], 0);
+
+    let ids = match errs["ids"] {
+        ValidationErrorsKind::List(ref list) => list,
+        ref kind => panic!("Expected a list of errors, found {:?}", kind),
+    };
+    assert_eq!(ids.keys().collect::<Vec<_>>(), vec![&2, &3]);
+    assert_eq!(ids[&3].field_errors()["__all__"][0].code, "unique");
+}
+
+#[test]
+fn unique_by_key_of_nested_structs() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(unique(by = "crate::sku", message = "SKUs must be unique"))]
+        #[validate]
+        items: Vec<LineItem>,
+    }
+
+    let s = TestStruct {
+        items: vec![LineItem { sku: "A1".to_string() }, LineItem { sku: "A1".to_string() }],
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let errs = res.unwrap_err().into_errors();
+    let items = match errs["items"] {
+        ValidationErrorsKind::List(ref list) => list,
+        ref kind => panic!("Expected a list of errors, found {:?}", kind),
+    };
+    assert_eq!(items.len(), 1);
+    let err = &items[&1].field_errors()["__all__"][0];
+    assert_eq!(err.clone().message.unwrap(), "SKUs must be unique");
+
+    // nested errors are still reported when the items are unique
+    let s = TestStruct {
+        items: vec![LineItem { sku: "A1".to_string() }, LineItem { sku: String::new() }],
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let errs = res.unwrap_err().into_errors();
+    let items = match errs["items"] {
+        ValidationErrorsKind::List(ref list) => list,
+        ref kind => panic!("Expected a list of errors, found {:?}", kind),
+    };
+    assert!(items[&1].field_errors().contains_key("sku"));
+}
+
+#[test]
+fn duplicates_are_merged_with_the_nested_errors() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(unique(by = "crate::sku"), nested)]
+        items: Vec<LineItem>,
+    }
+
+    let s = TestStruct {
+        items: vec![
+            LineItem { sku: String::new() },
+            LineItem { sku: "A1".to_string() },
+            LineItem { sku: "A1".to_string() },
+        ],
+    };
+    let errs = s.validate().unwrap_err().into_errors();
+    let items = match errs["items"] {
+        ValidationErrorsKind::List(ref list) => list,
+        ref kind => panic!("Expected a list of errors, found {:?}", kind),
+    };
+    assert_eq!(items.keys().collect::<Vec<_>>(), vec![&0, &2]);
+    assert_eq!(items[&0].field_errors()["sku"][0].code, "length");
+    assert_eq!(items[&2].field_errors()["__all__"][0].code, "unique");
+
+    // an item can have both errors
+    let s = TestStruct {
+        items: vec![LineItem { sku: String::new() }, LineItem { sku: String::new() }],
+    };
+    let errs = s.validate().unwrap_err().into_errors();
+    let items = match errs["items"] {
+        ValidationErrorsKind::List(ref list) => list,
+        ref kind => panic!("Expected a list of errors, found {:?}", kind),
+    };
+    assert_eq!(items[&1].field_errors()["sku"][0].code, "length");
+    assert_eq!(items[&1].field_errors()["__all__"][0].code, "unique");
+}
+
+#[test]
+fn duplicates_are_field_errors_when_the_field_has_other_errors() {
+    #[derive(Debug, Validate)]
+    struct LengthFirst {
+        #[validate(length(max = 2), unique)]
+        ids: Vec<u32>,
+    }
+
+    #[derive(Debug, Validate)]
+    struct UniqueFirst {
+        #[validate(unique, length(max = 2))]
+        ids: Vec<u32>,
+    }
+
+    let errs = LengthFirst { ids: vec![1, 2, 1] }.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs["ids"].len(), 2);
+    assert_eq!(errs["ids"][0].code, "length");
+    assert_eq!(errs["ids"][1].code, "unique");
+    assert_eq!(errs["ids"][1].params["index"], 2);
+    assert_eq!(errs["ids"][1].params["duplicate_of"], 0);
+
+    let errs = UniqueFirst { ids: vec![1, 2, 1] }.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs["ids"].len(), 2);
+    assert_eq!(errs["ids"][0].code, "length");
+    assert_eq!(errs["ids"][1].code, "unique");
+    assert_eq!(errs["ids"][1].params["index"], 2);
+
+    // the duplicates are still a list when they are the only errors
+    let errs = UniqueFirst { ids: vec![1, 1] }.validate().unwrap_err().into_errors();
+    assert!(matches!(errs["ids"], ValidationErrorsKind::List(_)));
+}
+
+#[test]
+fn duplicates_are_added_to_the_errors_of_a_custom_validator() {
+    fn no_zero(ids: &[u32]) -> Result<(), Vec<ValidationError>> {
+        if ids.contains(&0) {
+            return Err(vec![ValidationError::new("zero"), ValidationError::new("reserved")]);
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(unique, custom = "no_zero")]
+        ids: Vec<u32>,
+    }
+
+    let errs = TestStruct { ids: vec![0, 0] }.validate().unwrap_err();
+    let codes = errs.field_errors()["ids"].iter().map(|e| e.code.clone()).collect::<Vec<_>>();
+    assert_eq!(codes, vec!["zero", "reserved", "unique"]);
+}
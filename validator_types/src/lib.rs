@@ -1,5 +1,5 @@
 use proc_macro2::Span;
-use syn::{Expr, Type};
+use syn::{Expr, Lit, Type};
 
 /// Contains all the validators that can be used
 ///
@@ -9,18 +9,36 @@ use syn::{Expr, Type};
 pub enum Validator {
     Email,
     Url,
+    Hostname,
+    Domain {
+        /// Whether the domain can start with a `*.` wildcard
+        wildcard: bool,
+        /// Whether the domain has to end with a known public suffix
+        public_suffix: bool,
+    },
     Custom {
         /// This is the name of the function that should be called
         function: String,
         /// This is the argument type that can be passed in with a macro
         argument: Box<Option<CustomArgument>>,
+        /// Whether the struct is passed to the function after the field
+        with_parent: bool,
     },
     // String is the name of the field to match
     MustMatch(String),
-    // value is a &str or a HashMap<String, ..>
-    Contains(String),
+    // The needle: a literal or the path to a const/static
+    Contains(ValueOrPath<Lit>),
     // No implementation in this crate, it's all in validator_derive
-    Regex(String),
+    Regex {
+        /// Either the path to a static Regex/RegexSet or an inline pattern
+        regex: ValueOrPath<String>,
+        /// Whether the value must NOT match
+        negate: bool,
+        /// Only for inline patterns
+        case_insensitive: bool,
+        /// Set when the path is to a RegexSet
+        set: Option<RegexSetMode>,
+    },
     Range {
         min: Option<ValueOrPath<f64>>,
         max: Option<ValueOrPath<f64>>,
@@ -30,17 +48,172 @@ pub enum Validator {
         min: Option<ValueOrPath<u64>>,
         max: Option<ValueOrPath<u64>>,
         equal: Option<ValueOrPath<u64>>,
+        /// How strings are measured, `None` for the default of counting chars
+        mode: Option<LengthMode>,
     },
     #[cfg(feature = "card")]
     CreditCard,
     #[cfg(feature = "phone")]
     Phone,
-    Nested,
+    Nested {
+        /// The argument given to `validate_args` of the nested struct, taken from the ones of
+        /// the parent struct
+        argument: Box<Option<CustomArgument>>,
+        /// Whether the nested struct is given the context of the parent struct
+        context: bool,
+        /// How many levels of nested values are validated, starting with the field's value
+        max_depth: Option<usize>,
+    },
     #[cfg(feature = "unic")]
     NonControlCharacter,
-    Required,
+    Required {
+        /// Whether an empty or blank value counts as missing
+        empty_check: Option<EmptyCheck>,
+    },
     RequiredNested,
-    DoesNotContain(String),
+    DoesNotContain(ValueOrPath<Lit>),
+    // ascii, alphanumeric, alpha, numeric, lowercase, uppercase, printable
+    CharClass {
+        class: CharClass,
+        /// Whether non-ASCII characters are rejected
+        ascii_only: bool,
+    },
+    StartsWith {
+        /// The value has to start with one of them
+        needles: Vec<String>,
+        /// Whether the needles were given with `any(..)`
+        any: bool,
+        case_insensitive: bool,
+    },
+    EndsWith {
+        /// The value has to end with one of them
+        needles: Vec<String>,
+        /// Whether the needles were given with `any(..)`
+        any: bool,
+        case_insensitive: bool,
+    },
+    Unique {
+        /// The path to a function returning the key to compare items with, the items
+        /// themselves are compared if not set
+        by: Option<String>,
+    },
+    // non_empty, not_blank
+    EmptyCheck(EmptyCheck),
+    // Either literals or the path to a const slice/array
+    OneOf(ValueOrPath<Vec<Lit>>),
+    NoneOf(ValueOrPath<Vec<Lit>>),
+}
+
+/// Mirrors `validator::LengthMode`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LengthMode {
+    Bytes,
+    Chars,
+    Graphemes,
+    Utf16,
+}
+
+impl LengthMode {
+    pub fn from_name(name: &str) -> Option<LengthMode> {
+        match name {
+            "bytes" => Some(LengthMode::Bytes),
+            "chars" => Some(LengthMode::Chars),
+            "graphemes" => Some(LengthMode::Graphemes),
+            "utf16" => Some(LengthMode::Utf16),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            LengthMode::Bytes => "bytes",
+            LengthMode::Chars => "chars",
+            LengthMode::Graphemes => "graphemes",
+            LengthMode::Utf16 => "utf16",
+        }
+    }
+}
+
+/// Mirrors `validator::CharClass`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CharClass {
+    Ascii,
+    Alphanumeric,
+    Alpha,
+    Numeric,
+    Lowercase,
+    Uppercase,
+    Printable,
+}
+
+impl CharClass {
+    pub fn from_name(name: &str) -> Option<CharClass> {
+        match name {
+            "ascii" => Some(CharClass::Ascii),
+            "alphanumeric" => Some(CharClass::Alphanumeric),
+            "alpha" => Some(CharClass::Alpha),
+            "numeric" => Some(CharClass::Numeric),
+            "lowercase" => Some(CharClass::Lowercase),
+            "uppercase" => Some(CharClass::Uppercase),
+            "printable" => Some(CharClass::Printable),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            CharClass::Ascii => "ascii",
+            CharClass::Alphanumeric => "alphanumeric",
+            CharClass::Alpha => "alpha",
+            CharClass::Numeric => "numeric",
+            CharClass::Lowercase => "lowercase",
+            CharClass::Uppercase => "uppercase",
+            CharClass::Printable => "printable",
+        }
+    }
+}
+
+/// The `non_empty` and `not_blank` validators, also usable as options of `required`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmptyCheck {
+    NonEmpty,
+    NotBlank,
+}
+
+impl EmptyCheck {
+    pub fn from_name(name: &str) -> Option<EmptyCheck> {
+        match name {
+            "non_empty" => Some(EmptyCheck::NonEmpty),
+            "not_blank" => Some(EmptyCheck::NotBlank),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            EmptyCheck::NonEmpty => "non_empty",
+            EmptyCheck::NotBlank => "not_blank",
+        }
+    }
+}
+
+/// How many patterns of a RegexSet have to match for the `regex` validator to pass
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegexSetMode {
+    Any,
+    All,
+    None,
+}
+
+impl RegexSetMode {
+    pub fn from_name(name: &str) -> Option<RegexSetMode> {
+        match name {
+            "any" => Some(RegexSetMode::Any),
+            "all" => Some(RegexSetMode::All),
+            "none" => Some(RegexSetMode::None),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -76,28 +249,39 @@ impl Validator {
             Validator::MustMatch(_) => "must_match",
             Validator::Email => "email",
             Validator::Url => "url",
+            Validator::Hostname => "hostname",
+            Validator::Domain { .. } => "domain",
             Validator::Custom { .. } => "custom",
             Validator::Contains(_) => "contains",
-            Validator::Regex(_) => "regex",
+            Validator::Regex { .. } => "regex",
             Validator::Range { .. } => "range",
             Validator::Length { .. } => "length",
             #[cfg(feature = "card")]
             Validator::CreditCard => "credit_card",
             #[cfg(feature = "phone")]
             Validator::Phone => "phone",
-            Validator::Nested => "nested",
+            Validator::Nested { .. } => "nested",
             #[cfg(feature = "unic")]
             Validator::NonControlCharacter => "non_control_character",
-            Validator::Required => "required",
+            Validator::Required { .. } => "required",
             Validator::RequiredNested => "required_nested",
             Validator::DoesNotContain(_) => "does_not_contain",
+            Validator::CharClass { class, .. } => class.name(),
+            Validator::StartsWith { .. } => "starts_with",
+            Validator::EndsWith { .. } => "ends_with",
+            Validator::Unique { .. } => "unique",
+            Validator::EmptyCheck(check) => check.name(),
+            Validator::OneOf(_) => "one_of",
+            Validator::NoneOf(_) => "none_of",
         }
     }
 
     /// This returns the defined custom argument if it was defined
     pub fn get_custom_argument(&self) -> Option<&CustomArgument> {
         match self {
-            Validator::Custom { argument, .. } => (**argument).as_ref(),
+            Validator::Custom { argument, .. } | Validator::Nested { argument, .. } => {
+                (**argument).as_ref()
+            }
             _ => None,
         }
     }
@@ -105,7 +289,9 @@ impl Validator {
     /// This returns the defined custom argument if it was defined
     pub fn get_custom_argument_mut(&mut self) -> Option<&mut CustomArgument> {
         match self {
-            Validator::Custom { argument, .. } => (**argument).as_mut(),
+            Validator::Custom { argument, .. } | Validator::Nested { argument, .. } => {
+                (**argument).as_mut()
+            }
             _ => None,
         }
     }